@@ -0,0 +1,153 @@
+// Known answers for each day's input files, kept next to them in `data/dayN/answers` so every
+// solver can be rechecked after a refactor. One answer per line, comments start with `#`:
+//
+//     # file  part  answer
+//     input   1     1390
+//     test    2     10
+//
+// A file of `-` stands for the day's built-in input, for the days that don't read a file.
+
+use std::any::Any;
+use std::panic;
+use std::path::Path;
+
+use input::{day_path, read_day_file};
+use parsing::{tokens, ParseError};
+use solution::Solver;
+
+pub const MANIFEST: &str = "answers";
+pub const BUILT_IN: &str = "-";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct KnownAnswer {
+  pub file: String,
+  pub part: u8,
+  pub answer: String
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Outcome {
+  Pass,
+  // holds the answer the solver actually gave
+  Mismatch(String),
+  // the input couldn't be read or parsed, or the solver panicked
+  Fail(String)
+}
+
+pub fn parse(content: &str) -> Result<Vec<KnownAnswer>, ParseError> {
+  let mut known = vec![];
+
+  for (idx, line) in content.lines().enumerate() {
+    let trimmed = line.trim();
+    if trimmed.is_empty() || trimmed.starts_with('#') { continue; }
+
+    let columns = tokens(line);
+    if columns.len() != 3 {
+      return Err(ParseError::new(trimmed, "a line like `input 1 1390'").on_line(idx + 1).from_column(columns[0].0));
+    }
+
+    let (part_column, part_str) = columns[1];
+    let part = match part_str {
+      "1" => 1,
+      "2" => 2,
+      _ => return Err(ParseError::new(part_str, "part 1 or 2").on_line(idx + 1).from_column(part_column))
+    };
+
+    known.push(KnownAnswer {
+      file: String::from(columns[0].1),
+      part: part,
+      answer: String::from(columns[2].1)
+    });
+  }
+
+  return Ok(known);
+}
+
+// `None` if the day has no manifest yet
+pub fn load(day: u8) -> Option<Result<Vec<KnownAnswer>, ParseError>> {
+  if !Path::new(&day_path(day, MANIFEST)).exists() {
+    return None;
+  }
+  return Some(parse(&read_day_file(day, MANIFEST)));
+}
+
+pub fn check(solver: &dyn Solver, known: &KnownAnswer) -> Outcome {
+  let content = if known.file == BUILT_IN {
+    String::new()
+  } else {
+    let path = day_path(solver.day(), &known.file);
+    if !Path::new(&path).exists() {
+      return Outcome::Fail(format!("{} doesn't exist", path));
+    }
+    read_day_file(solver.day(), &known.file)
+  };
+
+  // a solver falling over on one file shouldn't stop the rest being checked
+  let solved = panic::catch_unwind(panic::AssertUnwindSafe(|| solver.solve(known.part, &content)));
+
+  match solved {
+    Ok(Ok(answer)) => {
+      let actual = answer.to_string();
+      if actual == known.answer {
+        return Outcome::Pass;
+      }
+      return Outcome::Mismatch(actual);
+    },
+    Ok(Err(e)) => return Outcome::Fail(e.to_string()),
+    Err(payload) => return Outcome::Fail(format!("panicked: {}", panic_message(&payload)))
+  }
+}
+
+fn panic_message(payload: &Box<dyn Any + Send>) -> String {
+  if let Some(s) = payload.downcast_ref::<&str>() {
+    return String::from(*s);
+  }
+  if let Some(s) = payload.downcast_ref::<String>() {
+    return s.to_owned();
+  }
+  return String::from("unknown cause");
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use days;
+
+  #[test]
+  fn parse_happy() {
+    let known = parse("# file part answer\n\ninput\t1\t1390\ntest 2 tknk\n").unwrap();
+    assert_eq!(known.len(), 2);
+    assert_eq!(known[1], KnownAnswer { file: String::from("test"), part: 2, answer: String::from("tknk") });
+  }
+
+  #[test]
+  fn parse_malformed() {
+    let e = parse("input 1 1390\ninput 3 5").unwrap_err();
+    assert_eq!((e.line, e.column), (2, 7));
+
+    let e = parse("input 1").unwrap_err();
+    assert_eq!(e.expected, "a line like `input 1 1390'");
+  }
+
+  #[test]
+  fn check_outcomes() {
+    let day9 = days::find(9).unwrap();
+    let known = |file: &str, answer: &str| KnownAnswer { file: String::from(file), part: 1, answer: String::from(answer) };
+
+    assert_eq!(check(day9, &known("test2", "6")), Outcome::Pass);
+    assert_eq!(check(day9, &known("test2", "7")), Outcome::Mismatch(String::from("6")));
+    assert_eq!(check(day9, &known("no_such_file", "6")), Outcome::Fail(String::from("data/day9/no_such_file doesn't exist")));
+  }
+
+  // every example file is a regression fixture, the full inputs are left to `advent verify`
+  // since some of them take minutes
+  #[test]
+  fn examples_match_their_manifests() {
+    for solver in days::all() {
+      let known = load(solver.day()).expect("every day has a manifest").unwrap();
+      for k in known.iter().filter(|k| k.file != "input" && k.file != BUILT_IN) {
+        assert_eq!(check(solver, k), Outcome::Pass, "day {} {} part {}", solver.day(), k.file, k.part);
+      }
+    }
+  }
+}
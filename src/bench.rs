@@ -0,0 +1,151 @@
+// Times parse, part 1 and part 2 of a day separately over a number of iterations, and writes
+// the results out as text for reading or JSON/CSV for comparing between commits. Times in the
+// JSON and CSV are whole nanoseconds.
+
+use std::time::Duration;
+
+use solution::{SolveError, Solver};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Stats {
+  pub min: Duration,
+  pub median: Duration,
+  pub max: Duration
+}
+
+impl Stats {
+  // panics on no samples, there's nothing sensible to report
+  pub fn of(samples: &[Duration]) -> Stats {
+    let mut sorted = samples.to_vec();
+    sorted.sort();
+
+    let mid = sorted.len() / 2;
+    let median = if sorted.len() % 2 == 0 {
+      (sorted[mid - 1] + sorted[mid]) / 2
+    } else {
+      sorted[mid]
+    };
+
+    return Stats { min: sorted[0], median: median, max: sorted[sorted.len() - 1] };
+  }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Bench {
+  pub day: u8,
+  pub iterations: usize,
+  pub parse: Stats,
+  pub part1: Stats,
+  pub part2: Stats
+}
+
+impl Bench {
+  // each stage with its name, in the order they run
+  pub fn stages(&self) -> [(&'static str, Stats); 3] {
+    return [("parse", self.parse), ("part1", self.part1), ("part2", self.part2)];
+  }
+}
+
+pub fn run(solver: &dyn Solver, input: &str, iterations: usize) -> Result<Bench, SolveError> {
+  let mut parse = vec![];
+  let mut part1 = vec![];
+  let mut part2 = vec![];
+
+  for _ in 0..iterations {
+    let timed = solver.solve_timed(input)?;
+    parse.push(timed.parse);
+    part1.push(timed.part1);
+    part2.push(timed.part2);
+  }
+
+  return Ok(Bench {
+    day: solver.day(),
+    iterations: iterations,
+    parse: Stats::of(&parse),
+    part1: Stats::of(&part1),
+    part2: Stats::of(&part2)
+  });
+}
+
+// one aligned row per stage, in milliseconds
+pub fn to_text(benches: &[Bench]) -> String {
+  let mut out = format!("{:>3}  {:<5}  {:>12}  {:>12}  {:>12}\n", "day", "stage", "min ms", "median ms", "max ms");
+  for b in benches {
+    for (name, s) in b.stages().iter() {
+      out.push_str(&format!("{:>3}  {:<5}  {:>12.3}  {:>12.3}  {:>12.3}\n", b.day, name, millis(s.min), millis(s.median), millis(s.max)));
+    }
+  }
+  return out;
+}
+
+pub fn to_csv(benches: &[Bench]) -> String {
+  let mut out = String::from("day,stage,iterations,min_ns,median_ns,max_ns\n");
+  for b in benches {
+    for (name, s) in b.stages().iter() {
+      out.push_str(&format!("{},{},{},{},{},{}\n", b.day, name, b.iterations, s.min.as_nanos(), s.median.as_nanos(), s.max.as_nanos()));
+    }
+  }
+  return out;
+}
+
+// an array with one object per day
+pub fn to_json(benches: &[Bench]) -> String {
+  let mut days = vec![];
+  for b in benches {
+    let mut stages = vec![];
+    for (name, s) in b.stages().iter() {
+      stages.push(format!("\"{}\": {{\"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+                          name, s.min.as_nanos(), s.median.as_nanos(), s.max.as_nanos()));
+    }
+    days.push(format!("  {{\"day\": {}, \"iterations\": {}, {}}}", b.day, b.iterations, stages.join(", ")));
+  }
+  return format!("[\n{}\n]\n", days.join(",\n"));
+}
+
+fn millis(d: Duration) -> f64 {
+  return d.as_secs_f64() * 1000.0;
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use days;
+
+  fn ms(n: u64) -> Duration {
+    return Duration::from_millis(n);
+  }
+
+  fn sample() -> Bench {
+    let s = Stats { min: ms(1), median: ms(2), max: ms(4) };
+    return Bench { day: 9, iterations: 3, parse: s, part1: s, part2: s };
+  }
+
+  #[test]
+  fn stats_odd_and_even() {
+    assert_eq!(Stats::of(&[ms(5), ms(1), ms(3)]), Stats { min: ms(1), median: ms(3), max: ms(5) });
+    assert_eq!(Stats::of(&[ms(4), ms(1), ms(2), ms(8)]), Stats { min: ms(1), median: ms(3), max: ms(8) });
+  }
+
+  #[test]
+  fn run_times_every_iteration() {
+    let b = run(days::find(9).unwrap(), "{{<a>},{<b>}}", 3).unwrap();
+    assert_eq!(b.day, 9);
+    assert_eq!(b.iterations, 3);
+    assert!(b.parse.min <= b.parse.median && b.parse.median <= b.parse.max);
+  }
+
+  #[test]
+  fn csv() {
+    let csv = to_csv(&[sample()]);
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[2], "9,part1,3,1000000,2000000,4000000");
+  }
+
+  #[test]
+  fn json() {
+    let json = to_json(&[sample()]);
+    assert!(json.starts_with("[\n  {\"day\": 9, \"iterations\": 3, \"parse\": {\"min_ns\": 1000000, "));
+    assert!(json.ends_with("\"max_ns\": 4000000}}\n]\n"));
+  }
+}
@@ -0,0 +1,411 @@
+use std::fmt;
+use std::io;
+use std::io::{Read, Seek, SeekFrom};
+
+use parsing::ParseError;
+use solution::{Answer, Solution};
+
+#[cfg(test)]
+use input::read_day_file;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+  type Input = String;
+
+  fn day(&self) -> u8 { return 1; }
+  fn title(&self) -> &'static str { return "Inverse Captcha"; }
+
+  fn parse(&self, input: &str) -> Result<String, ParseError> {
+    return Ok(input.to_owned());
+  }
+
+  fn warnings(&self, input: &str) -> Vec<String> {
+    return report(input, 10, Layout::Whole, &Offset::Fixed(1)).warnings.iter().map(|w| w.to_string()).collect();
+  }
+
+  fn part1(&self, input: &String) -> Answer {
+    return Answer::from(day1_part1(input.to_owned()));
+  }
+
+  fn part2(&self, input: &String) -> Answer {
+    return Answer::from(day1_part2(input.to_owned()));
+  }
+}
+
+pub fn day1_part1(input: String) -> u64 {
+  return captcha(&digits(&input), &Offset::Fixed(1)).sum;
+}
+
+pub fn day1_part2(input: String) -> u64 {
+  return captcha(&digits(&input), &Offset::Half).sum;
+}
+
+// which digit each one is compared against, wrapping around the end of the captcha
+pub enum Offset {
+  // k digits ahead, part 1 is `Fixed(1)`
+  Fixed(usize),
+  // halfway round, part 2
+  Half,
+  // any index from the index and the length, taken modulo the length
+  Custom(Box<dyn Fn(usize, usize) -> usize>)
+}
+
+impl Offset {
+  pub fn partner(&self, idx: usize, len: usize) -> usize {
+    match *self {
+      Offset::Fixed(k) => return (idx + k % len) % len,
+      Offset::Half => return (idx + len / 2) % len,
+      Offset::Custom(ref f) => return f(idx, len) % len
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Captcha {
+  pub sum: u64,
+  // the indices of the digits that matched their partner, in order
+  pub matches: Vec<usize>
+}
+
+// everything that isn't a digit is skipped
+pub fn digits(input: &str) -> Vec<u32> {
+  return input.chars().filter_map(|c| c.to_digit(10)).collect();
+}
+
+pub fn captcha(digits: &[u32], offset: &Offset) -> Captcha {
+  let mut sum = 0;
+  let mut matches = vec![];
+
+  for idx in 0..digits.len() {
+    if digits[idx] == digits[offset.partner(idx, digits.len())] {
+      sum += digits[idx] as u64;
+      matches.push(idx);
+    }
+  }
+
+  return Captcha { sum: sum, matches: matches };
+}
+
+// Whether the input is one captcha, or one per line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+  Whole,
+  PerLine
+}
+
+// A character that isn't whitespace or a digit in the captcha's radix, which is skipped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+  pub line: usize,
+  pub column: usize,
+  pub found: char,
+  pub radix: u32
+}
+
+impl fmt::Display for Warning {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "line {}, column {}: skipped `{}', not a base {} digit", self.line, self.column, self.found, self.radix)
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+  // the line each captcha starts on and its sum, a single one for the whole input
+  pub sums: Vec<(usize, u64)>,
+  pub total: u64,
+  pub warnings: Vec<Warning>
+}
+
+// Every captcha in the input, read as digits in any radix from 2 to 36 (`to_digit` panics
+// outside that). Blank lines are skipped when each line is its own captcha.
+pub fn report(input: &str, radix: u32, layout: Layout, offset: &Offset) -> Report {
+  let mut warnings = vec![];
+  let mut captchas = vec![];
+
+  for (idx, line) in input.lines().enumerate() {
+    let mut line_digits = vec![];
+    for (column, c) in line.chars().enumerate() {
+      match c.to_digit(radix) {
+        Some(d) => line_digits.push(d),
+        None if c.is_whitespace() => (),
+        None => warnings.push(Warning { line: idx + 1, column: column + 1, found: c, radix: radix })
+      }
+    }
+
+    match layout {
+      Layout::PerLine if line_digits.is_empty() => (),
+      Layout::PerLine => captchas.push((idx + 1, line_digits)),
+      Layout::Whole if captchas.is_empty() => captchas.push((1, line_digits)),
+      Layout::Whole => captchas[0].1.extend(line_digits)
+    }
+  }
+
+  let mut sums = vec![];
+  for (line, ds) in captchas {
+    let matched = captcha(&ds, offset).matches;
+    sums.push((line, matched.iter().map(|&i| ds[i] as u64).sum()));
+  }
+
+  return Report { total: sums.iter().map(|&(_, s)| s).sum(), sums: sums, warnings: warnings };
+}
+
+// How much of a stream is read at a time by the streaming captchas, which never hold more than
+// a couple of these however long the captcha is.
+const CHUNK: usize = 64 * 1024;
+
+// the part 1 captcha of everything `reader` gives, without holding on to it
+pub fn stream_next<R: Read>(mut reader: R) -> io::Result<u64> {
+  let mut buf = vec![0; CHUNK];
+  let mut first = None;
+  let mut previous = None;
+  let mut sum = 0;
+
+  loop {
+    let n = match reader.read(&mut buf) {
+      Ok(0) => break,
+      Ok(n) => n,
+      Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+      Err(e) => return Err(e)
+    };
+
+    for d in buf[..n].iter().filter_map(|&b| (b as char).to_digit(10)) {
+      if previous == Some(d) { sum += d as u64; }
+      if first.is_none() { first = Some(d); }
+      previous = Some(d);
+    }
+  }
+
+  // the last digit wraps round to the first
+  if let Some(f) = first {
+    if previous == Some(f) { sum += f as u64; }
+  }
+  return Ok(sum);
+}
+
+// The captcha for a fixed or half offset, in two passes: one to count the digits, then one with
+// a second cursor running `offset` digits ahead of the first. A custom offset can jump anywhere,
+// so it's refused; it needs the whole captcha in memory.
+pub fn stream_offset<R: Read + Seek>(mut reader: R, offset: &Offset) -> io::Result<u64> {
+  let mut counter = DigitCursor::new();
+  let mut len = 0;
+  while counter.next(&mut reader)?.is_some() {
+    len += 1;
+  }
+  if len == 0 { return Ok(0); }
+
+  let ahead = match *offset {
+    Offset::Fixed(k) => k % len,
+    Offset::Half => len / 2,
+    Offset::Custom(_) => return Err(io::Error::new(io::ErrorKind::InvalidInput, "a custom offset can't be streamed"))
+  };
+
+  let mut current = DigitCursor::new();
+  let mut partner = DigitCursor::new();
+  for _ in 0..ahead {
+    partner.next(&mut reader)?;
+  }
+
+  let mut sum = 0;
+  for _ in 0..len {
+    let d = current.next(&mut reader)?.expect("the stream shrank between passes");
+    let p = match partner.next(&mut reader)? {
+      Some(p) => p,
+      None => {
+        partner = DigitCursor::new();
+        partner.next(&mut reader)?.expect("the stream shrank between passes")
+      }
+    };
+    if d == p { sum += d as u64; }
+  }
+
+  return Ok(sum);
+}
+
+// Reads digits from wherever it left off in a shared, seekable stream, a chunk at a time, so
+// several can walk the same stream at different places.
+struct DigitCursor {
+  position: u64,
+  buf: Vec<u8>,
+  at: usize
+}
+
+impl DigitCursor {
+  fn new() -> DigitCursor {
+    return DigitCursor { position: 0, buf: vec![], at: 0 };
+  }
+
+  fn next<R: Read + Seek>(&mut self, reader: &mut R) -> io::Result<Option<u32>> {
+    loop {
+      while self.at < self.buf.len() {
+        let b = self.buf[self.at];
+        self.at += 1;
+        if let Some(d) = (b as char).to_digit(10) {
+          return Ok(Some(d));
+        }
+      }
+
+      reader.seek(SeekFrom::Start(self.position))?;
+      self.buf.resize(CHUNK, 0);
+      let n = reader.read(&mut self.buf)?;
+      self.buf.truncate(n);
+      self.position += n as u64;
+      self.at = 0;
+
+      if n == 0 { return Ok(None); }
+    }
+  }
+}
+
+// Part 1
+
+#[test]
+fn test_part1_ex1() {
+  assert!(day1_part1(String::from("1122")) == 3);
+}
+
+#[test]
+fn test_part1_ex2() {
+  assert!(day1_part1(String::from("1111")) == 4);
+}
+
+#[test]
+fn test_part1_ex3() {
+  assert!(day1_part1(String::from("1234")) == 0);
+}
+
+#[test]
+fn test_part1_ex4() {
+  assert!(day1_part1(String::from("91212129")) == 9);
+}
+
+// Part 2
+
+#[test]
+fn test_part2_ex1() {
+  assert!(day1_part2(String::from("1212")) == 6);
+}
+
+#[test]
+fn test_part2_ex2() {
+  assert!(day1_part2(String::from("1221")) == 0);
+}
+
+#[test]
+fn test_part2_ex3() {
+  assert!(day1_part2(String::from("123425")) == 4);
+}
+
+#[test]
+fn test_part2_ex4() {
+  assert!(day1_part2(String::from("123123")) == 12);
+}
+
+#[test]
+fn test_part2_ex5() {
+  assert!(day1_part2(String::from("12131415")) == 4);
+}
+
+// Any offset
+
+#[test]
+fn captcha_matches() {
+  let c = captcha(&digits("1122"), &Offset::Fixed(1));
+  assert_eq!(c, Captcha { sum: 3, matches: vec![0, 2] });
+
+  let c = captcha(&digits("123425"), &Offset::Half);
+  assert_eq!(c, Captcha { sum: 4, matches: vec![1, 4] });
+}
+
+#[test]
+fn captcha_other_offsets() {
+  assert_eq!(captcha(&digits("1231"), &Offset::Fixed(3)).matches, vec![0]);
+  assert_eq!(captcha(&digits("1212"), &Offset::Fixed(2)).sum, 6);
+
+  // each digit against the one mirrored from the end
+  let mirror = Offset::Custom(Box::new(|idx, len| len - 1 - idx));
+  assert_eq!(captcha(&digits("12321"), &mirror), Captcha { sum: 9, matches: vec![0, 1, 2, 3, 4] });
+  assert_eq!(captcha(&digits(""), &mirror), Captcha { sum: 0, matches: vec![] });
+}
+
+#[test]
+fn captcha_huge_offsets() {
+  let ds = digits("1231");
+  assert_eq!(captcha(&ds, &Offset::Fixed(usize::MAX)), captcha(&ds, &Offset::Fixed(usize::MAX % 4)));
+  assert_eq!(captcha(&ds, &Offset::Fixed(usize::MAX - 1)).matches, vec![]);
+  assert_eq!(Offset::Fixed(usize::MAX).partner(3, 4), 2);
+}
+
+#[test]
+fn captcha_sums_past_u32() {
+  // a run of 9s would need half a billion of them, but the digits can be anything
+  let big = vec![u32::MAX; 3];
+  assert_eq!(captcha(&big, &Offset::Fixed(1)).sum, 3 * u32::MAX as u64);
+
+  let nines = "9".repeat(1 << 22);
+  assert_eq!(day1_part1(nines.clone()), 9 << 22);
+  assert_eq!(day1_part2(nines), 9 << 22);
+}
+
+// Streaming
+
+#[test]
+fn streams_match_the_whole_captcha() {
+  use std::io::Cursor;
+
+  for example in ["1122", "1111", "1234", "91212129", "1212", "1221", "123425", "123123", "12131415", "7", ""].iter() {
+    let expected = captcha(&digits(example), &Offset::Fixed(1)).sum;
+    assert_eq!(stream_next(example.as_bytes()).unwrap(), expected, "{}", example);
+
+    for offset in [Offset::Half, Offset::Fixed(3)].iter() {
+      let expected = captcha(&digits(example), offset).sum;
+      assert_eq!(stream_offset(Cursor::new(example), offset).unwrap(), expected, "{}", example);
+    }
+  }
+
+  let custom = Offset::Custom(Box::new(|idx, _| idx));
+  assert!(stream_offset(Cursor::new("1122"), &custom).is_err());
+}
+
+#[test]
+fn streams_longer_than_a_chunk() {
+  use std::io::Cursor;
+
+  let input = read_day_file(1, "input");
+  let long: String = input.trim().repeat(CHUNK / input.len() + 3);
+  assert_eq!(stream_next(long.as_bytes()).unwrap(), captcha(&digits(&long), &Offset::Fixed(1)).sum);
+  assert_eq!(stream_offset(Cursor::new(&long), &Offset::Half).unwrap(), captcha(&digits(&long), &Offset::Half).sum);
+}
+
+// Radix and layout
+
+#[test]
+fn report_whole_and_per_line() {
+  let r = report("1122\n1111\n\n1234\n", 10, Layout::PerLine, &Offset::Fixed(1));
+  assert_eq!(r.sums, vec![(1, 3), (2, 4), (4, 0)]);
+  assert_eq!(r.total, 7);
+
+  // the lines run together: 112211111234
+  let r = report("1122\n1111\n\n1234\n", 10, Layout::Whole, &Offset::Fixed(1));
+  assert_eq!(r.sums, vec![(1, 7)]);
+  assert!(r.warnings.is_empty());
+}
+
+#[test]
+fn report_hex() {
+  let r = report("ffa1f", 16, Layout::Whole, &Offset::Fixed(1));
+  assert_eq!(r.total, 30);
+  // only the 1 is a decimal digit
+  assert_eq!(report("ffa1f", 10, Layout::Whole, &Offset::Fixed(1)).warnings.len(), 4);
+}
+
+#[test]
+fn report_warns_about_skipped_characters() {
+  let r = report("11x2\n 2-1", 10, Layout::PerLine, &Offset::Fixed(1));
+  assert_eq!(r.warnings, vec![
+    Warning { line: 1, column: 3, found: 'x', radix: 10 },
+    Warning { line: 2, column: 3, found: '-', radix: 10 }
+  ]);
+  assert_eq!(r.warnings[0].to_string(), "line 1, column 3: skipped `x', not a base 10 digit");
+  assert_eq!(Day1.warnings("12\n"), Vec::<String>::new());
+}
@@ -0,0 +1,74 @@
+use knot_hash;
+use knot_hash::Circular;
+use parsing::{fields, parse_as, ParseError};
+use solution::{Answer, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+  // the lengths for part 1, and the raw text that part 2 hashes
+  type Input = (Vec<usize>, String);
+
+  fn day(&self) -> u8 { return 10; }
+  fn title(&self) -> &'static str { return "Knot Hash"; }
+
+  fn parse(&self, input: &str) -> Result<(Vec<usize>, String), ParseError> {
+    return Ok((lengths(input)?, input.to_owned()));
+  }
+
+  fn part1(&self, input: &(Vec<usize>, String)) -> Answer {
+    return Answer::from(part1(&input.0));
+  }
+
+  fn part2(&self, input: &(Vec<usize>, String)) -> Answer {
+    return Answer::Hex(part2(&input.1));
+  }
+}
+
+// comma separated, each no longer than the 256 entry list they twist
+pub fn lengths(input: &str) -> Result<Vec<usize>, ParseError> {
+  let line = input.trim_end();
+  let mut result = vec![];
+  for (column, length_str) in fields(line, ',') {
+    let length = parse_as::<usize>(length_str).map_err(|e| e.from_column(column))?;
+    if length > 256 {
+      return Err(ParseError::new(length_str, "a length no more than 256").from_column(column));
+    }
+    result.push(length);
+  }
+  return Ok(result);
+}
+
+// one round over the lengths, then the product of the first two entries
+pub fn part1(lengths: &[usize]) -> i32 {
+  let mut c = Circular::standard();
+  for shift in lengths {
+    c.reverse_and_skip(*shift);
+  }
+  return c.get(0) as i32 * c.get(1) as i32;
+}
+
+
+pub fn part2(input: &str) -> Vec<u8> {
+  return knot_hash::hash(input);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn lengths_happy() {
+    assert_eq!(lengths("3,4,1,5\n"), Ok(vec![3, 4, 1, 5]));
+  }
+
+  #[test]
+  fn lengths_malformed() {
+    let e = lengths("3, 4,x").unwrap_err();
+    assert_eq!(e.column, 6);
+
+    let e = lengths("3,257").unwrap_err();
+    assert_eq!(e.column, 3);
+    assert_eq!(e.expected, "a length no more than 256");
+  }
+}
@@ -1,39 +1,53 @@
-use std::fs::File;
-use std::io::prelude::*;
 use std::cmp;
 
-fn main() {
-  println!("Advent of Code Day 11");
-  println!("");
-  println!("http://adventofcode.com/2017/day/11");
-  println!("");
+use parsing::{fields, ParseError};
+use solution::{Answer, Solution};
 
-  let mut file = File::open("data/day11/input").expect("file not found");
-  let mut content = String::new();
-  file.read_to_string(&mut content).expect("Something went wrong reading input file");
+pub struct Day11;
 
-  solve(&content);
+impl Solution for Day11 {
+  type Input = Vec<HexDirection>;
+
+  fn day(&self) -> u8 { return 11; }
+  fn title(&self) -> &'static str { return "Hex Ed"; }
+
+  fn parse(&self, input: &str) -> Result<Vec<HexDirection>, ParseError> {
+    let mut path = vec![];
+    for (column, dir) in fields(input.trim_end(), ',') {
+      path.push(HexDirection::parse(dir).map_err(|e| e.from_column(column))?);
+    }
+    return Ok(path);
+  }
+
+  fn part1(&self, input: &Vec<HexDirection>) -> Answer {
+    return Answer::from(walk(input).distance_to_origin());
+  }
+
+  fn part2(&self, input: &Vec<HexDirection>) -> Answer {
+    return Answer::from(walk(input).max_dist);
+  }
 }
 
-fn solve(input: &String) {
+// follows the whole path from the origin
+pub fn walk(path: &[HexDirection]) -> HexPointer {
   let mut hp = HexPointer::at_origin();
-  for dir in input.trim().split(',') {
-    hp.step(HexDirection::parse(dir)); 
+  for dir in path {
+    hp.step(*dir);
   }
-  println!("Part 1: {}", hp.distance_to_origin());
-  println!("Part 2: {}", hp.max_dist);
+  return hp;
 }
 
 // see http://keekerdc.com/2011/03/hexagon-grids-coordinate-systems-and-distance-calculations/
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-struct HexPointer {
+pub struct HexPointer {
   x: i32,
   y: i32,
   z: i32,
   max_dist: i32
 }
 
-enum HexDirection {
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum HexDirection {
   N,
   S,
   NE,
@@ -61,21 +75,21 @@ impl HexDirection {
     }
   }
 
-  pub fn parse(dir: &str) -> HexDirection {
+  pub fn parse(dir: &str) -> Result<HexDirection, ParseError> {
     if dir == "n" {
-      return HexDirection::N;
+      return Ok(HexDirection::N);
     } else if dir == "s" {
-      return HexDirection::S;
+      return Ok(HexDirection::S);
     } else if dir == "ne" {
-      return HexDirection::NE;
+      return Ok(HexDirection::NE);
     } else if dir == "nw" {
-      return HexDirection::NW;
+      return Ok(HexDirection::NW);
     } else if dir == "se" {
-      return HexDirection::SE;
+      return Ok(HexDirection::SE);
     } else if dir =="sw" {
-      return HexDirection::SW;
+      return Ok(HexDirection::SW);
     } else {
-      panic!("Unrecognized direction: ``{}''", dir);
+      return Err(ParseError::new(dir, "one of n, ne, se, s, sw or nw"));
     }
   }
 }
@@ -97,7 +111,7 @@ impl HexPointer {
     }
   }
 
-  pub fn distance_to_origin(&mut self) -> i32 {
+  pub fn distance_to_origin(&self) -> i32 {
     let c1 = cmp::max(self.x.abs(), self.y.abs());
     return cmp::max(c1, self.z.abs());
   }
@@ -144,4 +158,10 @@ mod tests {
 
     assert_eq!(hp.distance_to_origin(), 3);
   }
+  #[test]
+  fn test_parse_malformed() {
+    let e = Day11.parse("ne,ne,sx,s").unwrap_err();
+    assert_eq!(e.column, 7);
+    assert_eq!(e.text, "sx");
+  }
 }
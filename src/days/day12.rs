@@ -0,0 +1,58 @@
+use graph::Graph;
+use parsing::ParseError;
+use solution::{Answer, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+  type Input = Graph<i32>;
+
+  fn day(&self) -> u8 { return 12; }
+  fn title(&self) -> &'static str { return "Digital Plumber"; }
+
+  fn parse(&self, input: &str) -> Result<Graph<i32>, ParseError> {
+    return Graph::parse(input);
+  }
+
+  // the size of the group containing program 0
+  fn part1(&self, input: &Graph<i32>) -> Answer {
+    return Answer::from(input.spanning_set_for(0).len());
+  }
+
+  fn part2(&self, input: &Graph<i32>) -> Answer {
+    return Answer::from(input.number_of_groups());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use input::read_day_file;
+
+  #[test]
+  fn test_ex1_spanning() {
+    let content = read_day_file(12, "test");
+
+    let g : Graph<i32> = Graph::parse(&content).unwrap();
+    let spanning = g.spanning_set_for(0);
+
+    assert!(spanning.contains(&0));
+    assert!(spanning.contains(&2));
+    assert!(spanning.contains(&3));
+    assert!(spanning.contains(&4));
+    assert!(spanning.contains(&5));
+    assert!(spanning.contains(&6));
+
+    assert!(!spanning.contains(&1));
+  }
+
+  #[test]
+  fn test_ex1_groups() {
+    let content = read_day_file(12, "test");
+
+    let g : Graph<i32> = Graph::parse(&content).unwrap();
+
+    assert_eq!(g.number_of_groups(), 2);
+  }
+
+}
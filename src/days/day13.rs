@@ -1,64 +1,62 @@
-use std::fs::File;
-use std::io::prelude::*;
 use std::collections::HashMap;
-use std::str::FromStr;
 use std::cmp;
 
-fn parse_as<T : FromStr>(input: &String) -> T {
-  let parsed : Result<T, T::Err> = input.trim().parse();
-  match parsed {
-    Ok(d) => return d,
-    Err(_) => panic!("Failed to parse {}", input)
-  }
-}
-
-fn main() {
-  println!("Advent of Code Day 13");
-  println!("");
-  println!("http://adventofcode.com/2017/day/13");
-  println!("");
+use observe::Observer;
+use parsing::{fields, parse_as, ParseError};
+use solution::{Answer, Solution};
 
-  let mut file = File::open("data/day13/input").expect("file not found");
-  let mut content = String::new();
-  file.read_to_string(&mut content).expect("Something went wrong reading input file");
+pub struct Day13;
 
-  solve(&content);
-}
+impl Solution for Day13 {
+    type Input = Firewall;
 
-fn solve(input: &String) {
-    let mut fw = Firewall::parse(input);
-    println!("Part 1: {}", fw.execute_trip());
+    fn day(&self) -> u8 { return 13; }
+    fn title(&self) -> &'static str { return "Packet Scanners"; }
 
-    let mut idx = 3913000;
-    loop {
-        fw.reset();
-        idx += 1;
+    fn parse(&self, input: &str) -> Result<Firewall, ParseError> {
+        return Firewall::parse(input);
+    }
 
-        fw.execute_trip_with_delay(idx);
+    fn part1(&self, input: &Firewall) -> Answer {
+        return Answer::from(part1(input.to_owned()));
+    }
 
-        // caught a red herring, something weird in my implementation
-        if !fw.was_caught && idx > 191776 { break; }
+    fn part2(&self, input: &Firewall) -> Answer {
+        return Answer::from(part2(input.to_owned()));
+    }
+}
 
-        if idx % 1000 == 0 {
-            println!("All delays up to {} failed.", idx);
-        }
+// the severity of leaving straight away
+pub fn part1(mut fw: Firewall) -> i32 {
+    return fw.execute_trip();
+}
 
+// the smallest delay that gets through uncaught
+pub fn part2(fw: Firewall) -> i32 {
+    let mut delay = 0;
+    while fw.is_caught_with_delay(delay) {
+        delay += 1;
     }
-
-    println!("Part 2: {}", idx);
+    return delay;
 }
 
 
-#[derive(Debug, PartialEq, Eq)]
-struct Layer {
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Layer {
     depth: i32,
     range: i32,
     scanner_ptr: i32,
     scanner_direction: i32
 }
 
-#[derive(Debug, PartialEq, Eq)]
-struct Firewall {
+// one picosecond: the packet moves on to `depth` and every scanner sweeps
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Tick {
+    pub depth: i32
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Firewall {
     layers: HashMap<i32, Layer>,
     max_depth: i32,
     total_severity: i32, 
@@ -100,6 +98,11 @@ impl Layer {
         return self.scanner_ptr == 0;
     }
 
+    // whether the scanner is back at the top after `time` picoseconds, without sweeping there
+    pub fn is_caught_at(&self, time: i32) -> bool {
+        return time % (2 * (self.range - 1)) == 0;
+    }
+
     pub fn severity(&self) -> i32 {
         return self.depth * self.range;
     }
@@ -120,20 +123,29 @@ impl Firewall {
         };
     }
 
-    pub fn parse(input: &String) -> Firewall {
+    // lines look like `depth: range`
+    pub fn parse(input: &str) -> Result<Firewall, ParseError> {
         let mut fw = Firewall::empty();
 
-        for line in input.lines() {
-            let mut parsed = line.split(':');
-            let depth_str = String::from(parsed.next().unwrap());
-            let range_str = String::from(parsed.next().unwrap());
-            let depth = parse_as::<i32>(&depth_str);
-            let range = parse_as::<i32>(&range_str);
+        for (idx, line) in input.lines().enumerate() {
+            let parsed = fields(line, ':');
+            if parsed.len() != 2 {
+                return Err(ParseError::new(line.trim(), "a layer like `depth: range'").on_line(idx + 1));
+            }
+            let (depth_column, depth_str) = parsed[0];
+            let (range_column, range_str) = parsed[1];
+
+            let depth = parse_as::<i32>(depth_str).map_err(|e| e.on_line(idx + 1).from_column(depth_column))?;
+            let range = parse_as::<i32>(range_str).map_err(|e| e.on_line(idx + 1).from_column(range_column))?;
+            // a scanner needs somewhere to sweep to
+            if range < 2 {
+                return Err(ParseError::new(range_str, "a range of at least 2").on_line(idx + 1).from_column(range_column));
+            }
 
             fw.add_layer(Layer::new(depth, range));
         }
 
-        return fw;
+        return Ok(fw);
     }
 
     pub fn add_layer(&mut self, layer: Layer) {
@@ -145,19 +157,39 @@ impl Firewall {
     }
 
     pub fn execute_trip(&mut self) -> i32 {
+        return self.execute_trip_observed(&mut ());
+    }
+
+    pub fn execute_trip_observed<O: Observer<Firewall, Tick>>(&mut self, observer: &mut O) -> i32 {
         while !self.is_trip_complete() {
+            let tick = Tick { depth: self.position_ptr + 1 };
+            observer.before(self, &tick);
             self.step();
+            observer.after(self, &tick);
         }
         return self.total_severity;
     }
 
+    // the depth the packet has reached
+    pub fn position(&self) -> i32 {
+        return self.position_ptr;
+    }
+
+    // the severity of every catch so far
+    pub fn severity(&self) -> i32 {
+        return self.total_severity;
+    }
+
+    pub fn was_caught(&self) -> bool {
+        return self.was_caught;
+    }
+
     pub fn execute_trip_with_delay(&mut self, delay: i32) -> i32 {
         self.sweep_by_amt(delay);
 
         // this is to catch depth-0 catches
         if self.position_ptr == 0 {
-
-            self.was_caught == self.is_caught();
+            self.was_caught = self.is_caught();
             // this is a no-op, because the severity of a depth 0 catch is always 0
             // self.add_severity();
         }
@@ -165,8 +197,14 @@ impl Firewall {
         return self.execute_trip();
     }
 
+    // whether a packet leaving after `delay` meets any scanner, a packet reaches each layer at
+    // `delay + depth`
+    pub fn is_caught_with_delay(&self, delay: i32) -> bool {
+        return self.layers.values().any(|l| l.is_caught_at(delay + l.depth));
+    }
+
     pub fn reset(&mut self) {
-        for (_, mut layer) in self.layers.iter_mut() { layer.reset(); }
+        for layer in self.layers.values_mut() { layer.reset(); }
         self.position_ptr = 0;
         self.total_severity = 0;
         self.was_caught = false;
@@ -196,11 +234,11 @@ impl Firewall {
     }
 
     fn sweep_by_amt(&mut self, amt: i32) {
-        for (_, mut layer) in self.layers.iter_mut() { layer.sweep_by_amt(amt); }
+        for layer in self.layers.values_mut() { layer.sweep_by_amt(amt); }
     }
 
     fn sweep(&mut self) {
-        for (_, mut layer) in self.layers.iter_mut() { layer.sweep(); }
+        for layer in self.layers.values_mut() { layer.sweep(); }
     }
 
     fn step(&mut self) {
@@ -245,6 +283,13 @@ impl Firewall {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use input::read_day_file;
+
+    fn scanners(fw: &Firewall) -> Vec<(i32, i32)> {
+        let mut v : Vec<(i32, i32)> = fw.layers.values().map(|l| (l.depth, l.scanner_ptr)).collect();
+        v.sort();
+        return v;
+    }
 
     #[test]
     fn layer_test() {
@@ -289,22 +334,18 @@ mod tests {
 
     #[test]
     fn example_test() {
-        let mut file = File::open("data/day13/test").expect("file not found");
-        let mut content = String::new();
-        file.read_to_string(&mut content).expect("Something went wrong reading test file");
+        let content = read_day_file(13, "test");
 
-        let mut fw = Firewall::parse(&content);
+        let mut fw = Firewall::parse(&content).unwrap();
 
         assert_eq!(fw.execute_trip(), 24);
     }
 
     #[test]
     fn example_test_part2() {
-        let mut file = File::open("data/day13/test").expect("file not found");
-        let mut content = String::new();
-        file.read_to_string(&mut content).expect("Something went wrong reading test file");
+        let content = read_day_file(13, "test");
 
-        let mut fw = Firewall::parse(&content);
+        let mut fw = Firewall::parse(&content).unwrap();
 
         let mut idx = 0;
         loop {
@@ -314,7 +355,7 @@ mod tests {
             if !fw.was_caught { break; }
 
 
-            if idx > 10 { assert!(false); } // we've failed
+            assert!(idx <= 10, "no safe delay found"); // we've failed
         }
         assert_eq!(idx, 10);
         assert!(!fw.was_caught);
@@ -322,15 +363,14 @@ mod tests {
 
     #[test]
     fn dump() {
-        let mut file = File::open("data/day13/test").expect("file not found");
-        let mut content = String::new();
-        file.read_to_string(&mut content).expect("Something went wrong reading test file");
+        let content = read_day_file(13, "test");
 
-        let mut fw = Firewall::parse(&content);
+        let mut fw = Firewall::parse(&content).unwrap();
 
 
         fw.sweep_by_amt(10);
         fw.dump();
+        let by_amt = scanners(&fw);
 
         fw.reset();
 
@@ -346,7 +386,41 @@ mod tests {
         fw.sweep();
         fw.dump();
 
-        assert!(false);
+        assert_eq!(scanners(&fw), by_amt);
     }
-}
 
+    #[test]
+    fn part2_example() {
+        let content = read_day_file(13, "test");
+
+        assert_eq!(part2(Firewall::parse(&content).unwrap()), 10);
+    }
+
+    #[test]
+    fn malformed_layers() {
+        let e = Firewall::parse("0: 3\n1 2").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+
+        let e = Firewall::parse("0: 3\n4: 1").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 4));
+        assert_eq!(e.expected, "a range of at least 2");
+    }
+    #[test]
+    fn observed_trip() {
+        use observe::Observer;
+
+        // the depths the packet was caught at
+        struct Catches { depths: Vec<i32> }
+        impl Observer<Firewall, Tick> for Catches {
+            fn after(&mut self, fw: &Firewall, tick: &Tick) {
+                if fw.is_caught() { self.depths.push(tick.depth); }
+            }
+        }
+
+        let mut catches = Catches { depths: vec![] };
+        let mut fw = Firewall::parse(&read_day_file(13, "test")).unwrap();
+        assert_eq!(fw.execute_trip_observed(&mut catches), 24);
+        assert_eq!(catches.depths, vec![6]);
+        assert_eq!(fw.position(), 6);
+    }
+}
@@ -0,0 +1,57 @@
+use grid::BitBoard;
+use parsing::ParseError;
+use solution::{Answer, Solution};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+  type Input = String;
+
+  fn day(&self) -> u8 { return 14; }
+  fn title(&self) -> &'static str { return "Disk Defragmentation"; }
+
+  fn parse(&self, input: &str) -> Result<String, ParseError> {
+    return Ok(input.to_owned());
+  }
+
+  fn part1(&self, input: &String) -> Answer {
+    return Answer::from(part1(input));
+  }
+
+  fn part2(&self, input: &String) -> Answer {
+    return Answer::from(part2(input));
+  }
+}
+
+pub fn part1(input: &str) -> u32 {
+  let bb = BitBoard::from_hashes(input);
+  return bb.live_squares();
+}
+
+
+pub fn part2(input: &str) -> u32 {
+  let mut bb = BitBoard::from_hashes(input);
+  return bb.count_regions();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use input::read_day_file;
+
+    #[test]
+    fn day14_test() {
+        let input = read_day_file(14, "test");
+        let bb = BitBoard::from_hashes(&input);
+
+        assert_eq!(bb.live_squares(), 8108);
+    }
+
+    #[test]
+    fn day14_test_part2() {
+        let input = read_day_file(14, "test");
+        let mut bb = BitBoard::from_hashes(&input);
+
+        assert_eq!(bb.count_regions(), 1242);
+    }
+}
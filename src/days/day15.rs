@@ -0,0 +1,224 @@
+use parsing::{parse_as, tokens, ParseError};
+use solution::{Answer, Solution};
+
+// the factors for generators A and B respectively
+const FACTOR_A : u64 = 16807;
+const FACTOR_B : u64 = 48271;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = (u64, u64);
+
+    fn day(&self) -> u8 { return 15; }
+    fn title(&self) -> &'static str { return "Dueling Generators"; }
+
+    // the starting values for generators A and B
+    fn parse(&self, input: &str) -> Result<(u64, u64), ParseError> {
+        let mut seeds = vec![];
+        for (idx, line) in input.lines().enumerate() {
+            if line.trim().is_empty() { continue; }
+            seeds.push(parse_seed(line, seeds.len()).map_err(|e| e.on_line(idx + 1))?);
+        }
+
+        if seeds.len() != 2 {
+            return Err(ParseError::new(input.trim(), "a starting value for generators A and B"));
+        }
+        return Ok((seeds[0], seeds[1]));
+    }
+
+    fn part1(&self, input: &(u64, u64)) -> Answer {
+        return Answer::from(part1(input.0, input.1));
+    }
+
+    fn part2(&self, input: &(u64, u64)) -> Answer {
+        return Answer::from(part2(input.0, input.1));
+    }
+}
+
+// a line like `Generator A starts with 722', `which` counts generators from A
+fn parse_seed(line: &str, which: usize) -> Result<u64, ParseError> {
+    let name = ["A", "B"].get(which).cloned().unwrap_or("A or B");
+    let expected = format!("a line like `Generator {} starts with 722'", name);
+
+    match tokens(line).as_slice() {
+        [(_, "Generator"), (_, n), (_, "starts"), (_, "with"), (column, seed)] if *n == name => {
+            let value = parse_as::<u64>(seed).map_err(|e| e.from_column(*column))?;
+            // anything larger would overflow the first multiplication, and the puzzle's are all smaller
+            if value >= MODULUS {
+                let expected = format!("a starting value below {}", MODULUS);
+                return Err(ParseError::new(seed, &expected).from_column(*column));
+            }
+            return Ok(value);
+        },
+        [(column, _), ..] => return Err(ParseError::new(line.trim(), &expected).from_column(*column)),
+        [] => return Err(ParseError::new("", &expected))
+    }
+}
+
+pub fn part1(seed_a: u64, seed_b: u64) -> u64 {
+    let mut judge = Judge::new(vec![
+                               Generator::new(seed_a, FACTOR_A), //generator A
+                               Generator::new(seed_b, FACTOR_B), //generator B
+    ]);
+
+    for _ in 0..40000000 {
+        judge.judge_round();
+    }
+
+    return judge.score;
+}
+
+pub fn part2(seed_a: u64, seed_b: u64) -> u64 {
+    let mut judge = Judge::new(vec![
+                               Generator::new(seed_a, FACTOR_A), //generator A
+                               Generator::new(seed_b, FACTOR_B), //generator B
+    ]);
+
+    for _ in 0..5000000 {
+        judge.judge_part2_round();
+    }
+
+    return judge.score;
+}
+
+const MODULUS : u64 = 2147483647;
+const MASK : u64 = 0b1111111111111111;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Generator {
+    current_value: u64,
+    generation_factor: u64,
+    //history: Vec<u64>
+}
+
+impl Generator {
+    pub fn new(initial: u64, factor: u64) -> Generator {
+        return Generator {
+            // the same sequence follows from the remainder, which keeps `generate` from overflowing
+            current_value: initial % MODULUS,
+            generation_factor: factor
+            //history: vec![] 
+        };
+    }
+
+    pub fn generate(&mut self)  {
+        //self.history.push(self.current_value);
+        self.current_value = (self.current_value * self.generation_factor) % MODULUS;
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct Judge {
+    gen_a: Generator,
+    gen_b: Generator,
+    score: u64
+}
+
+impl Judge {
+
+    pub fn new(generators: Vec<Generator>) -> Judge {
+        return Judge {
+            gen_a: generators[0].to_owned(),
+            gen_b: generators[1].to_owned(),
+            score: 0
+        };
+    }
+
+    pub fn judge_round(&mut self) {
+        self.gen_a.generate();
+        self.gen_b.generate();
+
+        let lower_a = self.gen_a.current_value & MASK;
+        let lower_b = self.gen_b.current_value & MASK;
+
+        if lower_a == lower_b {
+            self.score += 1;
+        }
+    }
+
+    pub fn judge_part2_round(&mut self) {
+        loop {
+            self.gen_a.generate();
+            if self.gen_a.current_value % 4 == 0 { break; }
+        }
+
+        loop {
+            self.gen_b.generate();
+            if self.gen_b.current_value % 8 == 0 { break; }
+        }
+
+        let lower_a = self.gen_a.current_value & MASK;
+        let lower_b = self.gen_b.current_value & MASK;
+
+        if lower_a == lower_b {
+            self.score += 1;
+        }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn example() {
+        let mut judge = Judge::new(vec![
+           Generator::new(65, FACTOR_A), //generator A
+           Generator::new(8921, FACTOR_B), //generator B
+        ]);
+
+
+        for _ in 0..5 {
+            judge.judge_round();
+        }
+
+        assert_eq!(judge.score, 1);
+    }
+
+    #[test]
+    fn large_seeds() {
+        let mut huge = Generator::new(u64::MAX, FACTOR_A);
+        let mut reduced = Generator::new(u64::MAX % MODULUS, FACTOR_A);
+        huge.generate();
+        reduced.generate();
+        assert_eq!(huge, reduced);
+    }
+
+    #[test]
+    fn example_part2() {
+        let mut judge = Judge::new(vec![
+           Generator::new(65, FACTOR_A), //generator A
+           Generator::new(8921, FACTOR_B), //generator B
+        ]);
+
+
+        for _ in 0..5000000 {
+            judge.judge_part2_round();
+        }
+
+        assert_eq!(judge.score, 309);
+    }
+
+    #[test]
+    fn parse_seeds() {
+        assert_eq!(Day15.parse("Generator A starts with 65\nGenerator B starts with 8921\n"), Ok((65, 8921)));
+    }
+
+    #[test]
+    fn parse_malformed_seeds() {
+        let e = Day15.parse("Generator A starts with 65\nGenerator B starts with x").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 25));
+
+        let e = Day15.parse("Generator B starts with 65\nGenerator A starts with 8921").unwrap_err();
+        assert_eq!(e.expected, "a line like `Generator A starts with 722'");
+
+        assert!(Day15.parse("Generator A starts with 65").is_err());
+
+        let e = Day15.parse("Generator A starts with 18446744073709551615\nGenerator B starts with 8921").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 25));
+        assert_eq!(e.expected, "a starting value below 2147483647");
+        assert!(Day15.parse("Generator A starts with 2147483647\nGenerator B starts with 8921").is_err());
+        assert_eq!(Day15.parse("Generator A starts with 2147483646\nGenerator B starts with 0"), Ok((2147483646, 0)));
+    }
+}
@@ -1,48 +1,47 @@
-#[macro_use] extern crate lazy_static;
-extern crate regex;
-
 use std::fmt;
-use std::fs::File;
-use std::io::prelude::*;
-use std::str::FromStr;
 
 use regex::Regex;
 
+use observe::Observer;
+use parsing::{fields, parse_as, ParseError};
+use solution::{Answer, Solution};
 
-fn main() {
-    println!("Advent of Code Day 16");
-    println!("");
-    println!("http://adventofcode.com/2017/day/16");
-    println!("");
+lazy_static! {
+    static ref SPIN: Regex = Regex::new(r"^s(\d+)$").unwrap();
+    static ref EXCHANGE_BY_IDX: Regex = Regex::new(r"^x(\d+)/(\d+)$").unwrap();
+    static ref EXCHANGE_BY_NAME: Regex = Regex::new(r"^p([a-p])/([a-p])$").unwrap();
+}
 
-	let mut file = File::open("data/day16/input").expect("file not found");
-	let mut content = String::new();
-	file.read_to_string(&mut content).expect("Something went wrong reading input file");
+pub struct Day16;
 
-	part1(&content);
-    part2(&content);
-}
+impl Solution for Day16 {
+    type Input = Dance;
 
+    fn day(&self) -> u8 { return 16; }
+    fn title(&self) -> &'static str { return "Permutation Promenade"; }
 
-lazy_static! {
-    static ref SPIN: Regex = Regex::new(r"s(\d+)").unwrap();
-    static ref EXCHANGE_BY_IDX: Regex = Regex::new(r"x(\d+)/(\d+)").unwrap();
-    static ref EXCHANGE_BY_NAME: Regex = Regex::new(r"p([a-p])/([a-p])").unwrap();
+    fn parse(&self, input: &str) -> Result<Dance, ParseError> {
+        return Dance::parse(input);
+    }
+
+    fn part1(&self, input: &Dance) -> Answer {
+        return Answer::from(part1(input).to_string());
+    }
+
+    fn part2(&self, input: &Dance) -> Answer {
+        return Answer::from(part2(input).to_string());
+    }
 }
 
-fn part1(input: &String) {
-    let mut d = Dance::parse(&input);
-    println!("Part 1 - no opt: {}", d.run());
-    d.optimize();
-    println!("Part 1 - opt: {}", d.run());
+pub fn part1(d: &Dance) -> Ring {
+    return d.run();
 }
 
-fn part2(input: &String) {
+// the dance repeated a billion times
+pub fn part2(d: &Dance) -> Ring {
 
     let default_ring = Ring::new();
     let mut r = Ring::new();
-    let mut d = Dance::parse(&input);
-
 
     let mut cycle : Option<i32> = None;
     let mut i = 0;
@@ -69,29 +68,30 @@ fn part2(input: &String) {
         }
     }
 
-    println!("Part 2 - order opt: {}", r);
+    return r;
 }
 
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
-enum Command {
+pub enum Command {
     SPIN(usize),
     EXCHANGE(usize, usize),
     PARTNER(Program, Program)
 }
 
-struct Dance {
+#[derive(Debug)]
+pub struct Dance {
     instructions: Vec<Command>
 }
 
 impl Dance {
-    pub fn parse(input: &String) -> Dance {
+    pub fn parse(input: &str) -> Result<Dance, ParseError> {
         let mut commands = vec![];
-        for command in input.trim().split(",") {
-            commands.push(Command::parse(&String::from(command)));
+        for (column, command) in fields(input.trim_end(), ',') {
+            commands.push(Command::parse(command).map_err(|e| e.from_column(column))?);
         }
 
-        return Dance { instructions: commands };
+        return Ok(Dance { instructions: commands });
     }
 
     pub fn run(&self) -> Ring {
@@ -101,8 +101,15 @@ impl Dance {
     }
 
     pub fn run_with_ring(&self, r: &mut Ring) {
+        self.run_observed(r, &mut ());
+    }
+
+    // each move is an event, the ring is the state it changes
+    pub fn run_observed<O: Observer<Ring, Command>>(&self, r: &mut Ring, observer: &mut O) {
         for c in &self.instructions {
-            r.execute(&c);
+            observer.before(r, c);
+            r.execute(c);
+            observer.after(r, c);
         }
     }
 
@@ -200,44 +207,44 @@ impl Dance {
 }
 
 impl Command {
-    fn parse(command: &String) -> Command {
+    pub fn parse(command: &str) -> Result<Command, ParseError> {
         if SPIN.is_match(command) {
             let parsed = SPIN.captures(command).unwrap();
-            let amt : usize = parse_as::<usize>(&String::from(&parsed[1]));
-            return Command::SPIN(amt);
+            let amt : usize = parse_as::<usize>(&parsed[1]).map_err(|e| e.from_column(2))?;
+            return Ok(Command::SPIN(amt));
         } else if EXCHANGE_BY_IDX.is_match(command) {
             let parsed = EXCHANGE_BY_IDX.captures(command).unwrap();
-            let i : usize = parse_as::<usize>(&String::from(&parsed[1]));
-            let j : usize = parse_as::<usize>(&String::from(&parsed[2]));
-            return Command::EXCHANGE(i,j);
+            let i : usize = Command::position(&parsed[1], 2)?;
+            let j : usize = Command::position(&parsed[2], parsed[1].len() + 3)?;
+            return Ok(Command::EXCHANGE(i,j));
         } else if EXCHANGE_BY_NAME.is_match(command) {
             let parsed = EXCHANGE_BY_NAME.captures(command).unwrap();
-            let i : Program = Program::from_string(&String::from(&parsed[1]));
-            let j : Program = Program::from_string(&String::from(&parsed[2]));
-            return Command::PARTNER(i,j);
+            let i : Program = Program::from_string(&parsed[1]).map_err(|e| e.from_column(2))?;
+            let j : Program = Program::from_string(&parsed[2]).map_err(|e| e.from_column(4))?;
+            return Ok(Command::PARTNER(i,j));
         } else {
-            panic!("Unrecognized command: {}", command);
+            return Err(ParseError::new(command, "a move like `s1', `x3/4' or `pe/b'"));
         }
 
     }
-}
 
-
-fn parse_as<T : FromStr>(input: &String) -> T {
-  let parsed : Result<T, T::Err> = input.trim().parse();
-  match parsed {
-    Ok(d) => return d,
-    Err(_) => panic!("Failed to parse {}", input)
-  }
+    // an index into the ring, found at `column` of the move
+    fn position(s: &str, column: usize) -> Result<usize, ParseError> {
+        match parse_as::<usize>(s) {
+            Ok(i) if i < 16 => return Ok(i),
+            _ => return Err(ParseError::new(s, "a position between 0 and 15").from_column(column))
+        }
+    }
 }
 
+
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
-struct Ring {
+pub struct Ring {
     entries: [Program; 16]
 }
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
-enum Program {
+pub enum Program {
     A,
     B,
     C,
@@ -257,25 +264,27 @@ enum Program {
 }
 
 impl Program {
-	fn from_string(s : &String) -> Program {
-        if *s == String::from("a") { return Program::A; }
-        else if *s == String::from("b") { return Program::B; }
-        else if *s == String::from("c") { return Program::C; }
-        else if *s == String::from("d") { return Program::D; }
-        else if *s == String::from("e") { return Program::E; }
-        else if *s == String::from("f") { return Program::F; }
-        else if *s == String::from("g") { return Program::G; }
-        else if *s == String::from("h") { return Program::H; }
-        else if *s == String::from("i") { return Program::I; }
-        else if *s == String::from("j") { return Program::J; }
-        else if *s == String::from("k") { return Program::K; }
-        else if *s == String::from("l") { return Program::L; }
-        else if *s == String::from("m") { return Program::M; }
-        else if *s == String::from("n") { return Program::N; }
-        else if *s == String::from("o") { return Program::O; }
-        else if *s == String::from("p") { return Program::P; }
-        else { panic!("Unrecognized program: {}", s) }
-	}
+    pub fn from_string(s : &str) -> Result<Program, ParseError> {
+        match s {
+            "a" => Ok(Program::A),
+            "b" => Ok(Program::B),
+            "c" => Ok(Program::C),
+            "d" => Ok(Program::D),
+            "e" => Ok(Program::E),
+            "f" => Ok(Program::F),
+            "g" => Ok(Program::G),
+            "h" => Ok(Program::H),
+            "i" => Ok(Program::I),
+            "j" => Ok(Program::J),
+            "k" => Ok(Program::K),
+            "l" => Ok(Program::L),
+            "m" => Ok(Program::M),
+            "n" => Ok(Program::N),
+            "o" => Ok(Program::O),
+            "p" => Ok(Program::P),
+            _ => Err(ParseError::new(s, "a program between a and p"))
+        }
+    }
 }
 
 impl Ring {
@@ -337,32 +346,32 @@ impl fmt::Display for Program {
 
     fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            Program::A => write!(f, "A"),
-            Program::B => write!(f, "B"),
-            Program::C => write!(f, "C"),
-            Program::D => write!(f, "D"),
-            Program::E => write!(f, "E"),
-            Program::F => write!(f, "F"),
-            Program::G => write!(f, "G"),
-            Program::H => write!(f, "H"),
-            Program::I => write!(f, "I"),
-            Program::J => write!(f, "J"),
-            Program::K => write!(f, "K"),
-            Program::L => write!(f, "L"),
-            Program::M => write!(f, "M"),
-            Program::N => write!(f, "N"),
-            Program::O => write!(f, "O"),
-            Program::P => write!(f, "P")
+            Program::A => write!(f, "a"),
+            Program::B => write!(f, "b"),
+            Program::C => write!(f, "c"),
+            Program::D => write!(f, "d"),
+            Program::E => write!(f, "e"),
+            Program::F => write!(f, "f"),
+            Program::G => write!(f, "g"),
+            Program::H => write!(f, "h"),
+            Program::I => write!(f, "i"),
+            Program::J => write!(f, "j"),
+            Program::K => write!(f, "k"),
+            Program::L => write!(f, "l"),
+            Program::M => write!(f, "m"),
+            Program::N => write!(f, "n"),
+            Program::O => write!(f, "o"),
+            Program::P => write!(f, "p")
         }
     }
 }
 
 impl fmt::Display for Ring {
     fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
-        for i in 0..16 {
-            write!(f, "{}", self.entries[i]);
+        for entry in self.entries.iter() {
+            write!(f, "{}", entry)?;
         }
-        write!(f,"")
+        return Ok(());
     }
 }
 
@@ -424,13 +433,63 @@ mod tests {
         assert_eq!(r.get(3), Program::F);
     }
 
+    #[test]
+    fn display_matches_input_names() {
+        let mut r = Ring::new();
+        r.spin(1);
+
+        assert_eq!(r.to_string(), "pabcdefghijklmno");
+    }
+
     mod program {
         use super::*;
 
             #[test]
             fn from_string() {
-                let p = Program::from_string(&String::from("g"));
-                assert_eq!(p, Program::G);
+                let p = Program::from_string("g");
+                assert_eq!(p, Ok(Program::G));
             }
+
+            #[test]
+            fn from_string_malformed() {
+                assert!(Program::from_string("q").is_err());
+            }
+    }
+
+    #[test]
+    fn parse_commands() {
+        assert_eq!(Command::parse("s3"), Ok(Command::SPIN(3)));
+        assert_eq!(Command::parse("x15/0"), Ok(Command::EXCHANGE(15, 0)));
+        assert_eq!(Command::parse("pe/b"), Ok(Command::PARTNER(Program::E, Program::B)));
+    }
+
+    #[test]
+    fn parse_malformed_dance() {
+        let e = Dance::parse("s1,x3/4,pe/q").unwrap_err();
+        assert_eq!(e.column, 9);
+        assert_eq!(e.text, "pe/q");
+
+        let e = Dance::parse("s1,x3/16").unwrap_err();
+        assert_eq!(e.column, 7);
+        assert_eq!(e.expected, "a position between 0 and 15");
+    }
+    #[test]
+    fn observed_moves() {
+        use observe::Recorder;
+
+        // the start of the line after each move
+        struct Lines { seen: Vec<String> }
+        impl Observer<Ring, Command> for Lines {
+            fn after(&mut self, r: &Ring, _c: &Command) { self.seen.push(r.to_string()[..5].to_owned()); }
+        }
+
+        let d = Dance::parse("s1,x3/4,pe/b").unwrap();
+        let mut lines = Lines { seen: vec![] };
+        d.run_observed(&mut Ring::new(), &mut lines);
+        assert_eq!(lines.seen, vec!["pabcd", "pabdc", "paedc"]);
+
+        let mut recorder = Recorder::new();
+        d.run_observed(&mut Ring::new(), &mut recorder);
+        assert_eq!(recorder.events[0], Command::SPIN(1));
     }
 }
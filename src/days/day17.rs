@@ -1,33 +1,50 @@
 use std::fmt;
-use std::io::prelude::*;
 use std::collections::LinkedList;
 
-fn main() {
-    println!("Advent of Code Day 17");
-    println!("");
-    println!("http://adventofcode.com/2017/day/17");
-    println!("");
+use observe::Observer;
+use parsing::{parse_as, ParseError};
+use solution::{Answer, Solution};
 
-    part1(304);
-    part2(304);
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = usize;
+
+    fn day(&self) -> u8 { return 17; }
+    fn title(&self) -> &'static str { return "Spinlock"; }
+
+    // the number of steps the spinlock takes between inserts
+    fn parse(&self, input: &str) -> Result<usize, ParseError> {
+        return parse_as::<usize>(input);
+    }
+
+    fn part1(&self, input: &usize) -> Answer {
+        return Answer::from(part1(*input));
+    }
+
+    fn part2(&self, input: &usize) -> Answer {
+        return Answer::from(part2(*input));
+    }
 }
 
-fn part1(amt: usize) {
+// the value after 2017 once it has been inserted
+pub fn part1(amt: usize) -> u32 {
     let mut c = spinlocker_walker(amt, 2018);
     c.next();
-    println!("Part 1: {}", c.pointer);
+    return c.pointer;
 }
 
-fn part2(amt: usize) {
+// the value after 0 once 50000000 has been inserted
+pub fn part2(amt: usize) -> u32 {
     let mut c = spinlocker_walker(amt, 5000001);
     while c.pointer != 0 { c.next(); }
     c.next();
-    println!("Part 1: {}", c.pointer);
+    return c.pointer;
 }
 
 
 #[derive(PartialEq, Eq, Debug, Clone)]
-struct CircularBuffer<T> {
+pub struct CircularBuffer<T> {
     left: LinkedList<T>,
     pointer: T,
     right: LinkedList<T>
@@ -50,8 +67,8 @@ impl<T : Clone + Copy> CircularBuffer<T> {
     }
 
     fn reframe_right(&mut self) {
-        for e in self.left.to_owned() {
-            self.right.push_back(e);
+        for e in self.left.iter() {
+            self.right.push_back(*e);
         }
         self.left.clear();
     }
@@ -70,12 +87,17 @@ impl<T : Clone + Copy> CircularBuffer<T> {
     }
 
     fn reframe_left(&mut self) {
-        for e in self.right.to_owned() {
-            self.left.push_back(e);
+        for e in self.right.iter() {
+            self.left.push_back(*e);
         }
         self.right.clear();
     }
 
+    // the value under the pointer
+    pub fn current(&self) -> T {
+        return self.pointer;
+    }
+
     pub fn insert(&mut self, v: T) {
         self.left.push_back(self.pointer);
         self.pointer = v;
@@ -108,13 +130,26 @@ impl<T : fmt::Display + Clone + Copy> fmt::Display for CircularBuffer<T> {
     }
 }
 
-fn spinlocker_walker(step_amt: usize, step_max: usize) -> CircularBuffer<u32> {
+// one step: the spinlock has stepped forward and writes `value` after where it stopped
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Insert {
+    pub value: u32
+}
+
+pub fn spinlocker_walker(step_amt: usize, step_max: usize) -> CircularBuffer<u32> {
+    return spinlocker_walker_observed(step_amt, step_max, &mut ());
+}
+
+pub fn spinlocker_walker_observed<O: Observer<CircularBuffer<u32>, Insert>>(step_amt: usize, step_max: usize, observer: &mut O) -> CircularBuffer<u32> {
     let mut c = CircularBuffer::new(0);
     for i in 1..step_max {
         for _ in 0..step_amt {
            c.next();
         }
-        c.insert(i as u32);
+        let insert = Insert { value: i as u32 };
+        observer.before(&c, &insert);
+        c.insert(insert.value);
+        observer.after(&c, &insert);
     }
     return c;
 }
@@ -274,10 +309,24 @@ mod test {
         #[test]
         fn small_example() {
             let mut c = spinlocker_walker(3, 4);
-            for e in vec![3,1,0,2] {
+            for &e in [3,1,0,2].iter() {
                 assert_eq!(c.pointer, e);
                 c.next();
             }
         }
+
+        #[test]
+        fn observed_inserts() {
+            // the buffer after each insert, as the puzzle shows it
+            struct Buffers { seen: Vec<String> }
+            impl Observer<CircularBuffer<u32>, Insert> for Buffers {
+                fn after(&mut self, c: &CircularBuffer<u32>, _i: &Insert) { self.seen.push(c.to_string()); }
+            }
+
+            let mut buffers = Buffers { seen: vec![] };
+            let c = spinlocker_walker_observed(3, 4, &mut buffers);
+            assert_eq!(buffers.seen, vec!["[0, (1)]", "[0, (2), 1]", "[0, 2, (3), 1]"]);
+            assert_eq!(c.current(), 3);
+        }
     }
 }
@@ -0,0 +1,2564 @@
+use std::any;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Index, Range as Span};
+use std::slice;
+use std::str::FromStr;
+
+use num_bigint::{BigInt, BigUint};
+
+use parsing::{parse_as, tokens, ParseError};
+use solution::{Answer, Solution, SolveError};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+  type Input = Spreadsheet<i64>;
+
+  fn day(&self) -> u8 { return 2; }
+  fn title(&self) -> &'static str { return "Corruption Checksum"; }
+
+  // whitespace separated as in the puzzle, or comma separated as exported from elsewhere, with
+  // any formulas worked out
+  fn parse(&self, input: &str) -> Result<Spreadsheet<i64>, ParseError> {
+    let sheet = Spreadsheet::import(input, &Format::detect(input))?;
+    if !sheet.has_formulas() {
+      return Ok(sheet);
+    }
+    return sheet.computed().map_err(|e| {
+      let formula = sheet.cell(e.at()).and_then(|c| c.formula.as_ref()).expect("errors are at formulas");
+      e.to_parse_error(formula)
+    });
+  }
+
+  // rows part 2 can't, or can't unambiguously, find the divisible pair in
+  fn warnings(&self, input: &str) -> Vec<String> {
+    let sheet = match self.parse(input) {
+      Ok(sheet) => sheet,
+      Err(_) => return vec![]
+    };
+
+    let mut warnings = vec![];
+    for (row, d) in sheet.ambiguous_rows() {
+      match d {
+        Divisibility::Ambiguous(pairs) => {
+          let names: Vec<String> = pairs.iter().map(|p| format!("{}/{}", Address::new(row, p.dividend), Address::new(row, p.divisor))).collect();
+          warnings.push(format!("row {}: {} divisible pairs ({}), part 2 uses the first", row + 1, pairs.len(), names.join(", ")));
+        },
+        _ => warnings.push(format!("row {}: {}", row + 1, RowError::NoDivisiblePair))
+      }
+    }
+    return warnings;
+  }
+
+  fn try_part1(&self, input: &Spreadsheet<i64>) -> Result<Answer, SolveError> {
+    return input.part1().map(Answer::from).map_err(unsolvable);
+  }
+
+  fn try_part2(&self, input: &Spreadsheet<i64>) -> Result<Answer, SolveError> {
+    return input.part2().map(Answer::from).map_err(unsolvable);
+  }
+}
+
+// a sheet that parses can still have a row without a divisible pair, or a checksum too large
+fn unsolvable(e: ChecksumError) -> SolveError {
+  return SolveError::Unsolvable(e.to_string());
+}
+
+pub fn part1(input: String) -> Result<i64, SolveError> {
+  let sheet = Spreadsheet::new(input)?;
+
+  return sheet.part1().map_err(unsolvable);
+}
+
+pub fn part2(input: String) -> Result<i64, SolveError> {
+  let sheet = Spreadsheet::new(input)?;
+
+  return sheet.part2().map_err(unsolvable);
+}
+
+// What a sheet's cells can hold: i32, i64, u64, f64, `BigInt` and the like. Arithmetic is checked,
+// `None` when the result doesn't fit (or for floats isn't finite) and when dividing by zero.
+pub trait Number: Clone + PartialOrd + fmt::Debug + fmt::Display + FromStr {
+  fn zero() -> Self;
+  fn from_u8(n: u8) -> Self;
+
+  fn checked_add(&self, other: &Self) -> Option<Self>;
+  fn checked_sub(&self, other: &Self) -> Option<Self>;
+  fn checked_mul(&self, other: &Self) -> Option<Self>;
+  // rounding towards 0 for integers
+  fn checked_div(&self, other: &Self) -> Option<Self>;
+  fn checked_rem(&self, other: &Self) -> Option<Self>;
+  fn checked_neg(&self) -> Option<Self>;
+
+  fn is_zero(&self) -> bool {
+    return *self == Self::zero();
+  }
+
+  // whether every value is a whole number, so divisors can be counted up to
+  fn integral() -> bool {
+    return true;
+  }
+
+  // a cell's text
+  fn parse(text: &str) -> Result<Self, ParseError> {
+    return parse_as::<Self>(text);
+  }
+}
+
+macro_rules! integer {
+  ($($t:ty),*) => {$(
+    impl Number for $t {
+      fn zero() -> $t { return 0; }
+      fn from_u8(n: u8) -> $t { return n as $t; }
+
+      fn checked_add(&self, other: &$t) -> Option<$t> { return <$t>::checked_add(*self, *other); }
+      fn checked_sub(&self, other: &$t) -> Option<$t> { return <$t>::checked_sub(*self, *other); }
+      fn checked_mul(&self, other: &$t) -> Option<$t> { return <$t>::checked_mul(*self, *other); }
+      fn checked_div(&self, other: &$t) -> Option<$t> { return <$t>::checked_div(*self, *other); }
+      fn checked_rem(&self, other: &$t) -> Option<$t> { return <$t>::checked_rem(*self, *other); }
+      fn checked_neg(&self) -> Option<$t> { return <$t>::checked_neg(*self); }
+    }
+  )*}
+}
+
+integer!(i32, i64, i128, u32, u64);
+
+macro_rules! float {
+  ($($t:ty),*) => {$(
+    impl Number for $t {
+      fn zero() -> $t { return 0.0; }
+      fn from_u8(n: u8) -> $t { return n as $t; }
+
+      fn checked_add(&self, other: &$t) -> Option<$t> { return finite(self + other); }
+      fn checked_sub(&self, other: &$t) -> Option<$t> { return finite(self - other); }
+      fn checked_mul(&self, other: &$t) -> Option<$t> { return finite(self * other); }
+      fn checked_div(&self, other: &$t) -> Option<$t> { return finite(self / other); }
+      fn checked_rem(&self, other: &$t) -> Option<$t> { return finite(self % other); }
+      fn checked_neg(&self) -> Option<$t> { return Some(-self); }
+      fn integral() -> bool { return false; }
+
+      // `inf' and `NaN' parse, but aren't values a sheet can add up
+      fn parse(text: &str) -> Result<$t, ParseError> {
+        let n = parse_as::<$t>(text)?;
+        if !n.is_finite() {
+          return Err(ParseError::new(text.trim(), "a finite number"));
+        }
+        return Ok(n);
+      }
+    }
+  )*}
+}
+
+float!(f32, f64);
+
+// a float result, unless it's infinite or not a number
+fn finite<F: Into<f64> + Copy>(n: F) -> Option<F> {
+  if n.into().is_finite() { return Some(n); }
+  return None;
+}
+
+impl Number for BigInt {
+  fn zero() -> BigInt { return BigInt::from(0u8); }
+  fn from_u8(n: u8) -> BigInt { return BigInt::from(n); }
+
+  fn checked_add(&self, other: &BigInt) -> Option<BigInt> { return Some(self + other); }
+  fn checked_sub(&self, other: &BigInt) -> Option<BigInt> { return Some(self - other); }
+  fn checked_mul(&self, other: &BigInt) -> Option<BigInt> { return Some(self * other); }
+
+  fn checked_div(&self, other: &BigInt) -> Option<BigInt> {
+    if Number::is_zero(other) { return None; }
+    return Some(self / other);
+  }
+
+  fn checked_rem(&self, other: &BigInt) -> Option<BigInt> {
+    if Number::is_zero(other) { return None; }
+    return Some(self % other);
+  }
+
+  fn checked_neg(&self) -> Option<BigInt> { return Some(-self); }
+}
+
+impl Number for BigUint {
+  fn zero() -> BigUint { return BigUint::from(0u8); }
+  fn from_u8(n: u8) -> BigUint { return BigUint::from(n); }
+
+  fn checked_add(&self, other: &BigUint) -> Option<BigUint> { return Some(self + other); }
+
+  fn checked_sub(&self, other: &BigUint) -> Option<BigUint> {
+    if other > self { return None; }
+    return Some(self - other);
+  }
+
+  fn checked_mul(&self, other: &BigUint) -> Option<BigUint> { return Some(self * other); }
+
+  fn checked_div(&self, other: &BigUint) -> Option<BigUint> {
+    if Number::is_zero(other) { return None; }
+    return Some(self / other);
+  }
+
+  fn checked_rem(&self, other: &BigUint) -> Option<BigUint> {
+    if Number::is_zero(other) { return None; }
+    return Some(self % other);
+  }
+
+  fn checked_neg(&self) -> Option<BigUint> {
+    if Number::is_zero(self) { return Some(self.clone()); }
+    return None;
+  }
+}
+
+// Why a row has no checksum.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RowError {
+  // it doesn't fit in the sheet's number type, or the sheet's total stops fitting at this row
+  Overflow,
+  // for `EvenDivision`
+  NoDivisiblePair,
+  // for `Modular`
+  ZeroModulus,
+  // for `Range`, which needs a largest and a smallest value
+  Empty
+}
+
+impl fmt::Display for RowError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      RowError::Overflow => write!(f, "the checksum overflows"),
+      RowError::NoDivisiblePair => write!(f, "no two values divide evenly"),
+      RowError::ZeroModulus => write!(f, "a modulus of 0 leaves no values to take the sum modulo"),
+      RowError::Empty => write!(f, "an empty row has no largest or smallest value")
+    }
+  }
+}
+
+// A sheet's checksum that couldn't be worked out, at the row, from 0, where it went wrong.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ChecksumError {
+  pub row: usize,
+  pub error: RowError
+}
+
+impl fmt::Display for ChecksumError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "row {}: {}", self.row + 1, self.error)
+  }
+}
+
+impl Error for ChecksumError {}
+
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Delimiter {
+  Comma,
+  Tab,
+  // any run of spaces and tabs, as in the puzzle
+  Whitespace
+}
+
+impl Delimiter {
+  fn separates(&self, c: char) -> bool {
+    match *self {
+      Delimiter::Comma => return c == ',',
+      Delimiter::Tab => return c == '\t',
+      Delimiter::Whitespace => return c.is_whitespace()
+    }
+  }
+}
+
+// How a sheet exported from elsewhere is laid out. Fields can be quoted, with `""` for a quote
+// inside one, but a quoted field can't run onto the next line. Blank lines are skipped.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Format {
+  pub delimiter: Delimiter,
+  // the first row names the columns rather than holding numbers
+  pub header: bool,
+  // lines starting with this are skipped
+  pub comment: Option<char>
+}
+
+impl Format {
+  pub fn csv() -> Format {
+    return Format { delimiter: Delimiter::Comma, header: false, comment: Some('#') };
+  }
+
+  pub fn tsv() -> Format {
+    return Format { delimiter: Delimiter::Tab, header: false, comment: Some('#') };
+  }
+
+  pub fn whitespace() -> Format {
+    return Format { delimiter: Delimiter::Whitespace, header: false, comment: Some('#') };
+  }
+
+  pub fn with_header(self) -> Format {
+    return Format { header: true, ..self };
+  }
+
+  // comma separated if any line has a comma in it, otherwise whitespace separated, no header;
+  // comments don't count
+  pub fn detect(data: &str) -> Format {
+    let mut lines = without_bom(data).lines().map(|l| l.trim()).filter(|l| !l.is_empty() && !l.starts_with('#'));
+    if lines.any(|l| l.contains(',')) {
+      return Format::csv();
+    }
+    return Format::whitespace();
+  }
+}
+
+// spreadsheets saved as CSV often start with a byte order mark
+fn without_bom(data: &str) -> &str {
+  return data.strip_prefix('\u{feff}').unwrap_or(data);
+}
+
+#[derive(Debug,PartialEq,Eq,Clone)]
+pub struct Spreadsheet<T = i32> {
+	rows: Vec<SheetRow<T>>,
+	headers: Vec<String>
+}
+
+impl<T: Number> Spreadsheet<T> {
+  pub fn new(data: String) -> Result<Spreadsheet<T>, ParseError> {
+    let mut rows = vec![];
+    for (idx, line) in data.lines().enumerate() {
+      let row = SheetRow::new(String::from(line)).map_err(|e| e.on_line(idx + 1))?;
+      rows.push(SheetRow { cells: row.cells.into_iter().map(|c| c.at_line(idx + 1)).collect() });
+    }
+    return Ok(Spreadsheet { rows: rows, headers: vec![] });
+  }
+
+  pub fn import(data: &str, format: &Format) -> Result<Spreadsheet<T>, ParseError> {
+    let mut rows = vec![];
+    let mut headers = None;
+
+    for (idx, line) in without_bom(data).lines().enumerate() {
+      let trimmed = line.trim();
+      if trimmed.is_empty() { continue; }
+      if let Some(c) = format.comment {
+        if trimmed.starts_with(c) { continue; }
+      }
+
+      let fields = split_fields(line, format.delimiter).map_err(|e| e.on_line(idx + 1))?;
+      if format.header && headers.is_none() {
+        headers = Some(fields.into_iter().map(|(_, f)| f).collect());
+        continue;
+      }
+
+      let mut cells = vec![];
+      for (column, field) in fields {
+        let cell = SheetCell::new(field).map_err(|e| e.on_line(idx + 1).from_column(column))?;
+        cells.push(cell.at_line(idx + 1).at_column(column));
+      }
+      rows.push(SheetRow { cells: cells });
+    }
+
+    return Ok(Spreadsheet { rows: rows, headers: headers.unwrap_or_default() });
+  }
+
+  pub fn cell(&self, at: Address) -> Option<&SheetCell<T>> {
+    return self.rows.get(at.row).and_then(|r| r.cells.get(at.col));
+  }
+
+  // the cell at an `A1`-style address, `None` if it's malformed or out of bounds
+  pub fn at(&self, a1: &str) -> Option<&SheetCell<T>> {
+    return Address::parse(a1).ok().and_then(|at| self.cell(at));
+  }
+
+  // the length of the longest row
+  pub fn width(&self) -> usize {
+    return self.rows.iter().map(|r| r.len()).max().unwrap_or(0);
+  }
+
+  pub fn rows(&self) -> slice::Iter<'_, SheetRow<T>> {
+    return self.rows.iter();
+  }
+
+  // each cell down a column, from the top, passing over rows too short to reach it
+  pub fn column(&self, col: usize) -> impl Iterator<Item = &SheetCell<T>> {
+    return self.rows.iter().filter_map(move |r| r.cells.get(col));
+  }
+
+  // every column in turn, as `column`
+  pub fn columns(&self) -> impl Iterator<Item = Vec<&SheetCell<T>>> {
+    return (0..self.width()).map(move |c| self.column(c).collect());
+  }
+
+  // A block of the sheet, clipped to what's there, and without the headers. Formulas are copied
+  // as they are and still refer to where they did, so slice a `computed` sheet to take values.
+  pub fn slice(&self, rows: Span<usize>, cols: Span<usize>) -> Spreadsheet<T> {
+    let mut sliced = vec![];
+    for row in self.rows.iter().skip(rows.start).take(rows.end.saturating_sub(rows.start)) {
+      let cells = row.cells.iter().skip(cols.start).take(cols.end.saturating_sub(cols.start)).cloned().collect();
+      sliced.push(SheetRow { cells: cells });
+    }
+    return Spreadsheet { rows: sliced, headers: vec![] };
+  }
+
+  // Columns become rows, so checksums run down columns instead. A ragged sheet closes up: each new
+  // row is its column's cells from the rows long enough to have one. Headers are dropped.
+  pub fn transpose(&self) -> Spreadsheet<T> {
+    let rows = self.columns().map(|cells| SheetRow { cells: cells.into_iter().cloned().collect() }).collect();
+    return Spreadsheet { rows: rows, headers: vec![] };
+  }
+
+  pub fn has_formulas(&self) -> bool {
+    return self.rows.iter().any(|r| r.cells.iter().any(|c| c.formula.is_some()));
+  }
+
+  // a cell's value, working out only the formulas it depends on
+  pub fn evaluate(&self, at: Address) -> Result<T, FormulaError> {
+    return Evaluator::new(self).value(at);
+  }
+
+  // the same sheet with every formula replaced by its value, ready for checksums
+  pub fn computed(&self) -> Result<Spreadsheet<T>, FormulaError> {
+    let mut evaluator = Evaluator::new(self);
+    let mut rows = vec![];
+    for (r, row) in self.rows.iter().enumerate() {
+      let mut cells = vec![];
+      for c in 0..row.len() {
+        cells.push(SheetCell::from(evaluator.value(Address::new(r, c))?));
+      }
+      rows.push(SheetRow { cells: cells });
+    }
+    return Ok(Spreadsheet { rows: rows, headers: self.headers.clone() });
+  }
+
+  // the cells a formula reads directly, none for a plain value
+  pub fn dependencies(&self, at: Address) -> Vec<Address> {
+    match self.cell(at).and_then(|c| c.formula.as_ref()) {
+      Some(f) => return f.references(self),
+      None => return vec![]
+    }
+  }
+
+  // the formula cells that read this one directly
+  pub fn dependents(&self, at: Address) -> Vec<Address> {
+    let mut found = vec![];
+    for (r, row) in self.rows.iter().enumerate() {
+      for c in 0..row.len() {
+        let here = Address::new(r, c);
+        if self.dependencies(here).contains(&at) {
+          found.push(here);
+        }
+      }
+    }
+    return found;
+  }
+
+  // every cell in a run, in row order, leaving out any a ragged row doesn't have;
+  // a range stops at its first cell past the sheet, so it's never larger than the sheet
+  fn expand(&self, arg: &Arg<T>) -> Vec<Address> {
+    let mut found = vec![];
+    match *arg {
+      Arg::Value(_) => (),
+      Arg::Range(a, b) => {
+        for r in a.row.min(b.row)..(a.row.max(b.row) + 1) {
+          for c in a.col.min(b.col)..(a.col.max(b.col) + 1) {
+            let here = Address::new(r, c);
+            found.push(here);
+            if self.cell(here).is_none() { return found; }
+          }
+        }
+      },
+      Arg::Row(r) => {
+        let len = self.rows.get(r).map(|row| row.len()).unwrap_or(0);
+        found.extend((0..len).map(|c| Address::new(r, c)));
+      },
+      Arg::Col(c) => {
+        for (r, row) in self.rows.iter().enumerate() {
+          if c < row.len() { found.push(Address::new(r, c)); }
+        }
+      }
+    }
+    return found;
+  }
+
+  // the names from the header row, empty if there wasn't one
+  pub fn headers(&self) -> &[String] {
+    return &self.headers;
+  }
+
+  pub fn len(&self) -> usize {
+    return self.rows.len();
+  }
+
+  pub fn is_empty(&self) -> bool {
+    return self.rows.is_empty();
+  }
+
+  pub fn part1(&self) -> Result<T, ChecksumError> {
+    return self.checksum(&Range);
+  }
+
+  pub fn part2(&self) -> Result<T, ChecksumError> {
+    return self.checksum(&EvenDivision);
+  }
+
+  // every row's checksum, added up
+  pub fn checksum<C: RowChecksum<T> + ?Sized>(&self, strategy: &C) -> Result<T, ChecksumError> {
+    let mut sum = T::zero();
+    for (row, cells) in self.rows.iter().enumerate() {
+      let total = strategy.checksum(cells).and_then(|c| sum.checked_add(&c).ok_or(RowError::Overflow));
+      sum = total.map_err(|e| ChecksumError { row: row, error: e })?;
+    }
+    return Ok(sum);
+  }
+
+  // each row without exactly one divisible pair, by row from 0, for checking a sheet suits
+  // `EvenDivision`
+  pub fn ambiguous_rows(&self) -> Vec<(usize, Divisibility)> {
+    let mut found = vec![];
+    for (row, cells) in self.rows.iter().enumerate() {
+      match cells.divisibility() {
+        Divisibility::Unique(_) => (),
+        d => found.push((row, d))
+      }
+    }
+    return found;
+  }
+}
+
+// What a row contributes to a sheet's checksum. Any `Fn(&SheetRow<T>) -> T` is one too, for a
+// one-off.
+pub trait RowChecksum<T = i32> {
+  fn checksum(&self, row: &SheetRow<T>) -> Result<T, RowError>;
+
+  // the columns the checksum came from, for highlighting, none for those like `Sum` where every
+  // cell counts the same
+  fn contributors(&self, _row: &SheetRow<T>) -> Vec<usize> {
+    return vec![];
+  }
+}
+
+impl<T, F: Fn(&SheetRow<T>) -> T> RowChecksum<T> for F {
+  fn checksum(&self, row: &SheetRow<T>) -> Result<T, RowError> {
+    return Ok(self(row));
+  }
+}
+
+// the largest value less the smallest, part 1; an empty row is a `RowError`
+pub struct Range;
+
+// the quotient of the only two values where one divides the other, part 2, or of the first
+// dividend's first divisor where there are more
+pub struct EvenDivision;
+
+pub struct Sum;
+
+// the middle value, or the mean of the middle two (rounded down) for an even number of cells,
+// 0 for an empty row
+pub struct Median;
+
+// the sum, from 0 up to but not including the modulus (or its size, if it's negative); a modulus
+// of 0 is a `RowError`
+pub struct Modular<T> {
+  pub modulus: T
+}
+
+impl<T: Number> RowChecksum<T> for Range {
+  fn checksum(&self, row: &SheetRow<T>) -> Result<T, RowError> {
+    if row.is_empty() { return Err(RowError::Empty); }
+    return row.row_max().checked_sub(&row.row_min()).ok_or(RowError::Overflow);
+  }
+
+  // the leftmost of the smallest and of the largest
+  fn contributors(&self, row: &SheetRow<T>) -> Vec<usize> {
+    if row.is_empty() { return vec![]; }
+    let (min, max) = (row.row_min(), row.row_max());
+    let min_at = row.iter().position(|c| c.data == min).expect("the minimum is in the row");
+    let max_at = row.iter().position(|c| c.data == max).expect("the maximum is in the row");
+    let mut columns = vec![min_at, max_at];
+    columns.sort();
+    columns.dedup();
+    return columns;
+  }
+}
+
+impl<T: Number> RowChecksum<T> for EvenDivision {
+  fn checksum(&self, row: &SheetRow<T>) -> Result<T, RowError> {
+    match row.divisible_pairs().first() {
+      Some(pair) => return row[pair.dividend].data.checked_div(&row[pair.divisor].data).ok_or(RowError::Overflow),
+      None => return Err(RowError::NoDivisiblePair)
+    }
+  }
+
+  fn contributors(&self, row: &SheetRow<T>) -> Vec<usize> {
+    match row.divisible_pairs().first() {
+      Some(pair) => return vec![pair.divisor.min(pair.dividend), pair.divisor.max(pair.dividend)],
+      None => return vec![]
+    }
+  }
+}
+
+impl<T: Number> RowChecksum<T> for Sum {
+  fn checksum(&self, row: &SheetRow<T>) -> Result<T, RowError> {
+    let mut sum = T::zero();
+    for cell in row.iter() {
+      sum = sum.checked_add(&cell.data).ok_or(RowError::Overflow)?;
+    }
+    return Ok(sum);
+  }
+}
+
+impl<T: Number> RowChecksum<T> for Median {
+  fn checksum(&self, row: &SheetRow<T>) -> Result<T, RowError> {
+    if row.is_empty() { return Ok(T::zero()); }
+
+    let mut values: Vec<T> = row.iter().map(|c| c.value()).collect();
+    values.sort_by(|a, b| a.partial_cmp(b).expect("cells are comparable"));
+
+    let mid = values.len() / 2;
+    if values.len() % 2 == 0 {
+      return midpoint(&values[mid - 1], &values[mid]).ok_or(RowError::Overflow);
+    }
+    return Ok(values[mid].clone());
+  }
+
+  // the middle cell, or the middle two
+  fn contributors(&self, row: &SheetRow<T>) -> Vec<usize> {
+    let mut columns: Vec<usize> = (0..row.len()).collect();
+    columns.sort_by(|&a, &b| row[a].data.partial_cmp(&row[b].data).expect("cells are comparable"));
+
+    let mid = columns.len() / 2;
+    let mut middle = match columns.len() {
+      0 => vec![],
+      n if n % 2 == 0 => vec![columns[mid - 1], columns[mid]],
+      _ => vec![columns[mid]]
+    };
+    middle.sort();
+    return middle;
+  }
+}
+
+// the mean of two values, rounded down
+fn midpoint<T: Number>(a: &T, b: &T) -> Option<T> {
+  let two = T::from_u8(2);
+  let sum = a.checked_add(b)?;
+  let half = sum.checked_div(&two)?;
+  // integer division rounds towards 0, so a negative odd sum comes out one too high
+  if half.checked_mul(&two)? > sum {
+    return half.checked_sub(&T::from_u8(1));
+  }
+  return Some(half);
+}
+
+impl<T: Number> RowChecksum<T> for Modular<T> {
+  fn checksum(&self, row: &SheetRow<T>) -> Result<T, RowError> {
+    if self.modulus.is_zero() {
+      return Err(RowError::ZeroModulus);
+    }
+
+    let sum = Sum.checksum(row)?;
+    let rem = sum.checked_rem(&self.modulus).ok_or(RowError::Overflow)?;
+    if rem < T::zero() {
+      let size = if self.modulus < T::zero() { self.modulus.checked_neg() } else { Some(self.modulus.clone()) };
+      return size.and_then(|s| rem.checked_add(&s)).ok_or(RowError::Overflow);
+    }
+    return Ok(rem);
+  }
+}
+
+// Two cells of a row, by column, where one divides the other with nothing left over. When they
+// divide each other (the same size), the divisor is the one further left.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct Pair {
+  pub dividend: usize,
+  pub divisor: usize
+}
+
+impl Pair {
+  fn of<T: Number>(row: &SheetRow<T>, dividend: usize, divisor: usize) -> Pair {
+    if magnitude(&row[dividend].data) == magnitude(&row[divisor].data) {
+      return Pair { dividend: dividend.max(divisor), divisor: dividend.min(divisor) };
+    }
+    return Pair { dividend: dividend, divisor: divisor };
+  }
+}
+
+// a row as the division checksum sees it
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Divisibility {
+  Missing,
+  Unique(Pair),
+  // in the order of `SheetRow::divisible_pairs`
+  Ambiguous(Vec<Pair>)
+}
+
+// whether `divisor` goes into `n` exactly; 0 goes into nothing, but everything else goes into 0
+fn divides<T: Number>(divisor: &T, n: &T) -> bool {
+  if divisor.is_zero() { return false; }
+  match n.checked_rem(divisor) {
+    Some(r) => return r.is_zero(),
+    // only the most negative integer by -1, which leaves nothing
+    None => return true
+  }
+}
+
+// the size of a value, `None` for the most negative integer, which has no positive counterpart
+fn magnitude<T: Number>(n: &T) -> Option<T> {
+  if *n < T::zero() { return n.checked_neg(); }
+  return Some(n.clone());
+}
+
+impl<T: Number> Index<usize> for Spreadsheet<T> {
+  type Output = SheetRow<T>;
+
+  fn index(&self, index: usize) -> &SheetRow<T> {
+    if index >= self.rows.len() {
+      panic!("{} out of bounds for {:?}", index, self);
+    } else {
+      return &self.rows[index];
+    }
+  }
+}
+
+// `sheet[(row, col)]`, counting from 0
+impl<T: Number> Index<(usize, usize)> for Spreadsheet<T> {
+  type Output = SheetCell<T>;
+
+  fn index(&self, (row, col): (usize, usize)) -> &SheetCell<T> {
+    return &self[Address::new(row, col)];
+  }
+}
+
+impl<T: Number> Index<Address> for Spreadsheet<T> {
+  type Output = SheetCell<T>;
+
+  fn index(&self, at: Address) -> &SheetCell<T> {
+    match self.cell(at) {
+      Some(cell) => return cell,
+      None => panic!("{} out of bounds for {:?}", at, self)
+    }
+  }
+}
+
+// `sheet["B2"]`, panics on anything that isn't an address as well as on one that's out of bounds
+impl<'a, T: Number> Index<&'a str> for Spreadsheet<T> {
+  type Output = SheetCell<T>;
+
+  fn index(&self, a1: &'a str) -> &SheetCell<T> {
+    match Address::parse(a1) {
+      Ok(at) => return &self[at],
+      Err(e) => panic!("{}", e)
+    }
+  }
+}
+
+// How `Export` writes a sheet out.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Style {
+  Csv,
+  Markdown,
+  // padded so the columns line up, for reading in a terminal
+  Text
+}
+
+// A sheet written out for other tools and other people: a column numbering the rows, then the
+// cells, then a column for each checksum, with a footer totalling them. The sheet's headers name
+// its columns, or without any they're lettered as in formulas. Formulas are written as their
+// value, so export a `computed` sheet.
+//
+// Highlighting marks the cells each checksum came from, e.g. the smallest and largest for
+// `Range`: `**5**` in Markdown and `[5]` in text. CSV keeps its cells plain numbers and adds a
+// column of their addresses after each checksum instead.
+pub struct Export<'a, T: 'a> {
+  pub style: Style,
+  pub highlight: bool,
+  checksums: Vec<(String, &'a dyn RowChecksum<T>)>
+}
+
+impl<'a, T: Number> Export<'a, T> {
+  pub fn csv() -> Export<'a, T> {
+    return Export::new(Style::Csv);
+  }
+
+  pub fn markdown() -> Export<'a, T> {
+    return Export::new(Style::Markdown);
+  }
+
+  pub fn text() -> Export<'a, T> {
+    return Export::new(Style::Text);
+  }
+
+  pub fn new(style: Style) -> Export<'a, T> {
+    return Export { style: style, highlight: false, checksums: vec![] };
+  }
+
+  // a column headed `name`, after any added already
+  pub fn with_checksum(mut self, name: &str, strategy: &'a dyn RowChecksum<T>) -> Export<'a, T> {
+    self.checksums.push((String::from(name), strategy));
+    return self;
+  }
+
+  pub fn with_highlights(self) -> Export<'a, T> {
+    return Export { highlight: true, ..self };
+  }
+
+  pub fn render(&self, sheet: &Spreadsheet<T>) -> Result<String, ChecksumError> {
+    let width = sheet.width();
+    let addresses = self.highlight && self.style == Style::Csv;
+
+    let mut header = vec![String::from("row")];
+    for c in 0..width {
+      header.push(sheet.headers.get(c).cloned().unwrap_or_else(|| Address::column_name(c)));
+    }
+    for (name, _) in &self.checksums {
+      header.push(name.clone());
+      if addresses { header.push(format!("{} cells", name)); }
+    }
+
+    let mut body = vec![];
+    let mut totals = vec![T::zero(); self.checksums.len()];
+    for (r, row) in sheet.rows.iter().enumerate() {
+      let failed = |e| ChecksumError { row: r, error: e };
+
+      let mut highlighted = vec![false; row.len()];
+      let mut sums = vec![];
+      for (k, &(_, strategy)) in self.checksums.iter().enumerate() {
+        let sum = strategy.checksum(row).map_err(failed)?;
+        totals[k] = totals[k].checked_add(&sum).ok_or_else(|| failed(RowError::Overflow))?;
+
+        let from = if self.highlight { strategy.contributors(row) } else { vec![] };
+        for &c in &from {
+          if let Some(h) = highlighted.get_mut(c) { *h = true; }
+        }
+        let names: Vec<String> = from.iter().map(|&c| Address::new(r, c).to_string()).collect();
+        sums.push((sum, names.join(" ")));
+      }
+
+      let mut line = vec![(r + 1).to_string()];
+      for (cell, &h) in row.cells.iter().zip(highlighted.iter()) {
+        line.push(self.mark(cell.data.to_string(), h));
+      }
+      // a ragged row's missing cells
+      line.extend(vec![String::new(); width - row.len()]);
+      for (sum, names) in sums {
+        line.push(sum.to_string());
+        if addresses { line.push(names); }
+      }
+      body.push(line);
+    }
+
+    let mut footer = vec![String::from("total")];
+    footer.extend(vec![String::new(); width]);
+    for total in totals {
+      footer.push(total.to_string());
+      if addresses { footer.push(String::new()); }
+    }
+
+    match self.style {
+      Style::Csv => return Ok(csv_table(&header, &body, &footer)),
+      Style::Markdown => return Ok(markdown_table(&header, &body, &footer)),
+      Style::Text => return Ok(text_table(&header, &body, &footer))
+    }
+  }
+
+  fn mark(&self, value: String, highlighted: bool) -> String {
+    match self.style {
+      _ if !self.highlight => return value,
+      Style::Markdown if highlighted => return format!("**{}**", value),
+      Style::Text if highlighted => return format!("[{}]", value),
+      // the same width as a bracketed one, so the digits still line up
+      Style::Text => return format!(" {} ", value),
+      _ => return value
+    }
+  }
+}
+
+fn csv_table(header: &[String], body: &[Vec<String>], footer: &[String]) -> String {
+  let mut out = String::new();
+  for line in Some(header).into_iter().chain(body.iter().map(|l| &l[..])).chain(Some(footer)) {
+    let fields: Vec<String> = line.iter().map(|f| csv_field(f)).collect();
+    out.push_str(&fields.join(","));
+    out.push('\n');
+  }
+  return out;
+}
+
+// quoted, as `import` reads them, if it has anything in that would otherwise split it
+fn csv_field(field: &str) -> String {
+  if field.contains([',', '"', '\n']) || field.trim() != field {
+    return format!("\"{}\"", field.replace('"', "\"\""));
+  }
+  return String::from(field);
+}
+
+// every column but the row numbers right aligned, and the totals in bold
+fn markdown_table(header: &[String], body: &[Vec<String>], footer: &[String]) -> String {
+  let row = |cells: &[String]| {
+    let escaped: Vec<String> = cells.iter().map(|c| c.replace('|', "\\|")).collect();
+    format!("| {} |\n", escaped.join(" | "))
+  };
+
+  let mut out = row(header);
+  let mut rule = vec![String::from("---")];
+  rule.extend(vec![String::from("---:"); header.len() - 1]);
+  out.push_str(&format!("| {} |\n", rule.join(" | ")));
+  for line in body {
+    out.push_str(&row(line));
+  }
+  let bold: Vec<String> = footer.iter().map(|c| if c.is_empty() { String::new() } else { format!("**{}**", c) }).collect();
+  out.push_str(&row(&bold));
+  return out;
+}
+
+// two spaces between columns, the row numbers on the left and everything else on the right
+fn text_table(header: &[String], body: &[Vec<String>], footer: &[String]) -> String {
+  let lines: Vec<&[String]> = Some(header).into_iter().chain(body.iter().map(|l| &l[..])).chain(Some(footer)).collect();
+  let mut widths = vec![0; header.len()];
+  for line in &lines {
+    for (w, cell) in widths.iter_mut().zip(line.iter()) {
+      *w = (*w).max(cell.chars().count());
+    }
+  }
+
+  let mut out = String::new();
+  for line in &lines {
+    let mut padded = vec![];
+    for (c, cell) in line.iter().enumerate() {
+      if c == 0 {
+        padded.push(format!("{:<1$}", cell, widths[c]));
+      } else {
+        padded.push(format!("{:>1$}", cell, widths[c]));
+      }
+    }
+    out.push_str(padded.join("  ").trim_end());
+    out.push('\n');
+  }
+  return out;
+}
+
+// Each field in the line with the column it starts at, unquoted. A quoted field's column is its
+// opening quote's.
+fn split_fields(line: &str, delimiter: Delimiter) -> Result<Vec<(usize, String)>, ParseError> {
+  let chars: Vec<char> = line.chars().collect();
+  // spaces around a field that don't separate it from the next
+  let padding = |c: char| c.is_whitespace() && !delimiter.separates(c);
+
+  let mut fields = vec![];
+  let mut i = 0;
+  loop {
+    if delimiter == Delimiter::Whitespace {
+      while i < chars.len() && chars[i].is_whitespace() { i += 1; }
+      if i == chars.len() { break; }
+    } else {
+      while i < chars.len() && padding(chars[i]) { i += 1; }
+    }
+
+    let start = i;
+    let mut field = String::new();
+    if i < chars.len() && chars[i] == '"' {
+      i += 1;
+      loop {
+        if i == chars.len() {
+          let rest: String = chars[start..].iter().collect();
+          return Err(ParseError::new(&rest, "a closing `\"'").from_column(start + 1));
+        }
+        if chars[i] == '"' {
+          if i + 1 < chars.len() && chars[i + 1] == '"' {
+            field.push('"');
+            i += 2;
+            continue;
+          }
+          i += 1;
+          break;
+        }
+        field.push(chars[i]);
+        i += 1;
+      }
+
+      while i < chars.len() && padding(chars[i]) { i += 1; }
+      if i < chars.len() && !delimiter.separates(chars[i]) {
+        return Err(ParseError::new(&chars[i].to_string(), "a delimiter after the closing `\"'").from_column(i + 1));
+      }
+    } else {
+      while i < chars.len() && !delimiter.separates(chars[i]) {
+        field.push(chars[i]);
+        i += 1;
+      }
+      field = String::from(field.trim_end());
+    }
+
+    fields.push((start + 1, field));
+    if i == chars.len() { break; }
+    i += 1;
+  }
+
+  return Ok(fields);
+}
+
+// A number, or a formula starting with `=`. A formula cell's value is 0 until the sheet is
+// `computed`.
+#[derive(Debug,PartialEq,Eq,Clone)]
+pub struct SheetCell<T = i32> {
+	data: T,
+	formula: Option<Formula<T>>
+}
+
+impl<T: Number> SheetCell<T> {
+  pub fn new(data: String) -> Result<SheetCell<T>, ParseError> {
+    if data.trim_start().starts_with('=') {
+      return Ok(SheetCell { data: T::zero(), formula: Some(Formula::parse(&data)?) });
+    }
+    return T::parse(&data).map(SheetCell::from);
+  }
+
+  pub fn from(data: T) -> SheetCell<T> {
+    return SheetCell { data: data, formula: None };
+  }
+
+  pub fn value(&self) -> T {
+    return self.data.clone();
+  }
+
+  pub fn formula(&self) -> Option<&Formula<T>> {
+    return self.formula.as_ref();
+  }
+
+  // where a formula was read from, as for `ParseError`
+  fn at_line(mut self, line: usize) -> SheetCell<T> {
+    if let Some(ref mut f) = self.formula { f.line = line; }
+    return self;
+  }
+
+  fn at_column(mut self, column: usize) -> SheetCell<T> {
+    if let Some(ref mut f) = self.formula { f.column += column - 1; }
+    return self;
+  }
+}
+
+#[derive(Debug,PartialEq,Eq,Clone)]
+pub struct SheetRow<T = i32> {
+	cells: Vec<SheetCell<T>>
+}
+
+impl<T: Number> SheetRow<T> {
+  pub fn new(data: String) -> Result<SheetRow<T>, ParseError> {
+    let mut cells = vec![];
+    for (column, d) in tokens(&data) {
+      let cell = SheetCell::new(String::from(d)).map_err(|e| e.from_column(column))?;
+      cells.push(cell.at_column(column));
+    }
+    return Ok(SheetRow { cells: cells });
+  }
+
+  pub fn part1(&self) -> Result<T, RowError> {
+    return Range.checksum(self);
+  }
+
+  pub fn part2(&self) -> Result<T, RowError> {
+    return EvenDivision.checksum(self);
+  }
+
+  // Every pair where one value divides the other, by dividend then divisor column. For integers
+  // each value looks its divisors up in the sorted row, up to its square root, unless the row is
+  // narrower than that and it's checked against every other cell instead, so it's
+  // O(n min(n, sqrt(max) log n)): every pair for a narrow row of large values, fewer otherwise.
+  pub fn divisible_pairs(&self) -> Vec<Pair> {
+    let mut sorted: Vec<(&T, usize)> = self.cells.iter().enumerate().map(|(i, c)| (&c.data, i)).collect();
+    sorted.sort_by(|a, b| a.0.partial_cmp(b.0).expect("cells are comparable"));
+
+    let mut pairs = vec![];
+    for (i, cell) in self.cells.iter().enumerate() {
+      let n = &cell.data;
+      let looked_up = match magnitude(n) {
+        Some(ref size) if T::integral() && !n.is_zero() => self.divisors_of(i, size, &sorted, &mut pairs),
+        _ => false
+      };
+      if !looked_up {
+        for j in 0..self.cells.len() {
+          if i != j && divides(&self.cells[j].data, n) {
+            pairs.push(Pair::of(self, i, j));
+          }
+        }
+      }
+    }
+
+    pairs.sort();
+    pairs.dedup();
+    return pairs;
+  }
+
+  // The pairs with cell `i`, of size `size`, as the dividend, found by trying each `k` up to the
+  // square root and looking up `k`, `size / k` and their negatives. Returns false without trying
+  // any if that would take more tries than there are cells, which is when (cells + 1)² <= size.
+  fn divisors_of(&self, i: usize, size: &T, sorted: &[(&T, usize)], pairs: &mut Vec<Pair>) -> bool {
+    // a width that doesn't fit in `T`, or whose square doesn't, is wider than any square root
+    let wider = (self.cells.len() + 1).to_string().parse::<T>().ok();
+    if wider.and_then(|w| w.checked_mul(&w)).is_some_and(|square| square <= *size) {
+      return false;
+    }
+
+    let one = T::from_u8(1);
+    let mut k = one.clone();
+
+    while k.checked_mul(&k).is_some_and(|square| square <= *size) {
+      if divides(&k, size) {
+        let other = size.checked_div(&k).expect("k is a divisor");
+        for d in [Some(k.clone()), k.checked_neg(), Some(other.clone()), other.checked_neg()].iter().flatten() {
+          let from = sorted.partition_point(|&(x, _)| x < d);
+          for &(_, j) in sorted[from..].iter().take_while(|&&(x, _)| x == d) {
+            if j != i { pairs.push(Pair::of(self, i, j)); }
+          }
+        }
+      }
+
+      k = match k.checked_add(&one) {
+        Some(k) => k,
+        None => break
+      };
+    }
+    return true;
+  }
+
+  pub fn divisibility(&self) -> Divisibility {
+    let mut pairs = self.divisible_pairs();
+    match pairs.len() {
+      0 => return Divisibility::Missing,
+      1 => return Divisibility::Unique(pairs.remove(0)),
+      _ => return Divisibility::Ambiguous(pairs)
+    }
+  }
+
+  pub fn iter(&self) -> slice::Iter<'_, SheetCell<T>> {
+    return self.cells.iter();
+  }
+
+  pub fn len(&self) -> usize {
+    return self.cells.len();
+  }
+
+  pub fn is_empty(&self) -> bool {
+    return self.cells.is_empty();
+  }
+
+  pub fn row_max(&self) -> T {
+    let mut max = &self.cells.first().expect("an empty row has no maximum").data;
+    for cell in &self.cells {
+      if cell.data > *max {
+        max = &cell.data;
+      }
+    }
+    return max.clone();
+  }
+
+  pub fn row_min(&self) -> T {
+    let mut min = &self.cells.first().expect("an empty row has no minimum").data;
+    for cell in &self.cells {
+      if cell.data < *min {
+        min = &cell.data;
+      }
+    }
+    return min.clone();
+  }
+}
+
+impl<T> Index<usize> for SheetRow<T> {
+  type Output = SheetCell<T>;
+
+  fn index(&self, index: usize) -> &SheetCell<T> {
+    return &self.cells[index];
+  }
+}
+
+// A cell's place in the sheet, counting from 0. Written `A1`-style, column letters then row
+// number, so `Address { row: 1, col: 2 }` is `C2`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct Address {
+  pub row: usize,
+  pub col: usize
+}
+
+impl Address {
+  pub fn new(row: usize, col: usize) -> Address {
+    return Address { row: row, col: col };
+  }
+
+  // `A` is column 0, `Z` 25, `AA` 26 and so on
+  pub fn column_name(col: usize) -> String {
+    let mut letters = vec![];
+    let mut n = col + 1;
+    while n > 0 {
+      n -= 1;
+      letters.push((b'A' + (n % 26) as u8) as char);
+      n /= 26;
+    }
+    return letters.iter().rev().collect();
+  }
+
+  // `B2` and the like, column letters in either case then a row number from 1
+  pub fn parse(a1: &str) -> Result<Address, ParseError> {
+    let trimmed = a1.trim();
+    let digits_at = trimmed.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(trimmed.len());
+    let (letters, digits) = trimmed.split_at(digits_at);
+
+    let row = digits.parse::<usize>().ok().and_then(|r| r.checked_sub(1));
+    match (row, Address::column_number(letters)) {
+      (Some(row), Some(col)) if digits.chars().all(|c| c.is_ascii_digit()) => return Ok(Address::new(row, col)),
+      _ => return Err(ParseError::new(trimmed, "a cell like `B2'"))
+    }
+  }
+
+  // the column named by some letters, in either case
+  fn column_number(letters: &str) -> Option<usize> {
+    let mut n: usize = 0;
+    for c in letters.chars() {
+      if !c.is_ascii_alphabetic() { return None; }
+      let digit = (c.to_ascii_uppercase() as u8 - b'A') as usize + 1;
+      n = n.checked_mul(26)?.checked_add(digit)?;
+    }
+    return n.checked_sub(1);
+  }
+}
+
+impl fmt::Display for Address {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}{}", Address::column_name(self.col), self.row + 1)
+  }
+}
+
+// Why a formula couldn't be worked out; `at` is the formula's cell.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum FormulaError {
+  // each cell on the way round, starting and ending with the same one
+  Cycle(Vec<Address>),
+  NoSuchCell { at: Address, missing: Address },
+  DivideByZero(Address),
+  Overflow(Address),
+  // MIN or MAX of nothing
+  NoValues(Address)
+}
+
+impl FormulaError {
+  // the formula's cell
+  pub fn at(&self) -> Address {
+    match *self {
+      FormulaError::Cycle(ref path) => return path[0],
+      FormulaError::NoSuchCell { at, .. } => return at,
+      FormulaError::DivideByZero(at) | FormulaError::Overflow(at) | FormulaError::NoValues(at) => return at
+    }
+  }
+
+  // as a parse error, for the formula's text where it was read from
+  pub fn to_parse_error<T>(&self, formula: &Formula<T>) -> ParseError {
+    let expected = match *self {
+      FormulaError::Cycle(_) => format!("a formula that doesn't depend on itself ({})", self),
+      FormulaError::NoSuchCell { missing, .. } => format!("a formula referring only to cells that exist, not {}", missing),
+      FormulaError::DivideByZero(_) => String::from("a formula that doesn't divide by zero"),
+      FormulaError::Overflow(_) => format!("a formula whose value fits in type {}", any::type_name::<T>()),
+      FormulaError::NoValues(_) => String::from("MIN or MAX of at least one cell")
+    };
+    return ParseError::new(&formula.source, &expected).on_line(formula.line).from_column(formula.column);
+  }
+}
+
+impl fmt::Display for FormulaError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      FormulaError::Cycle(ref path) => {
+        let names: Vec<String> = path.iter().map(|a| a.to_string()).collect();
+        write!(f, "cycle {}", names.join(" -> "))
+      },
+      FormulaError::NoSuchCell { at, missing } => write!(f, "{} refers to {}, which doesn't exist", at, missing),
+      FormulaError::DivideByZero(at) => write!(f, "{} divides by zero", at),
+      FormulaError::Overflow(at) => write!(f, "{} overflows", at),
+      FormulaError::NoValues(at) => write!(f, "{} takes the MIN or MAX of no cells", at)
+    }
+  }
+}
+
+impl Error for FormulaError {}
+
+// The text of a formula cell, `=` and all, parsed. Line and column are where it was read from,
+// for reporting errors found when it's worked out.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Formula<T = i32> {
+  source: String,
+  expr: Expr<T>,
+  line: usize,
+  column: usize
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum BinaryOp {
+  Add,
+  Sub,
+  Mul,
+  Div
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Function {
+  Sum,
+  Min,
+  Max
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Expr<T> {
+  Number(T),
+  Ref(Address),
+  Neg(Box<Expr<T>>),
+  Binary(BinaryOp, Box<Expr<T>>, Box<Expr<T>>),
+  Call(Function, Vec<Arg<T>>)
+}
+
+// what a function can be given: a value, or a run of cells
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Arg<T> {
+  Value(Expr<T>),
+  // corner to corner, inclusive
+  Range(Address, Address),
+  // every cell in the row, `row 3`
+  Row(usize),
+  // every cell in the column, `col B`
+  Col(usize)
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Token {
+  // as written, to be read as the sheet's number type, or a row number
+  Number(String),
+  Cell(Address),
+  Name(String),
+  Symbol(char)
+}
+
+impl<T: Number> Formula<T> {
+  // `source` starts with `=`, after any spaces:
+  //
+  //     =A1+B2*2   =-(A1-3)/2   =SUM(A1:C1)   =MAX(row 3, 10)   =MIN(col B)   =A1*1.5
+  pub fn parse(source: &str) -> Result<Formula<T>, ParseError> {
+    let parser = FormulaParser { tokens: lex(source)?, pos: 0, open: 0, end: source.chars().count() + 1, numbers: PhantomData };
+    let expr = parser.parse()?;
+    return Ok(Formula { source: String::from(source.trim()), expr: expr, line: 1, column: 1 });
+  }
+
+  pub fn source(&self) -> &str {
+    return &self.source;
+  }
+
+  // the cells it reads directly, with runs of cells expanded to what's in the sheet
+  fn references(&self, sheet: &Spreadsheet<T>) -> Vec<Address> {
+    let mut found = vec![];
+    self.expr.references(sheet, &mut found);
+    return found;
+  }
+}
+
+impl<T: Number> Expr<T> {
+  fn references(&self, sheet: &Spreadsheet<T>, found: &mut Vec<Address>) {
+    match *self {
+      Expr::Number(_) => (),
+      Expr::Ref(a) => found.push(a),
+      Expr::Neg(ref e) => e.references(sheet, found),
+      Expr::Binary(_, ref l, ref r) => {
+        l.references(sheet, found);
+        r.references(sheet, found);
+      },
+      Expr::Call(_, ref args) => {
+        for arg in args {
+          match *arg {
+            Arg::Value(ref e) => e.references(sheet, found),
+            _ => found.extend(sheet.expand(arg))
+          }
+        }
+      }
+    }
+  }
+}
+
+// each token after the `=` with the column it starts at, counting from the start of `source`
+fn lex(source: &str) -> Result<Vec<(usize, Token)>, ParseError> {
+  let chars: Vec<char> = source.chars().collect();
+  let mut i = 0;
+  while i < chars.len() && chars[i].is_whitespace() { i += 1; }
+  if i == chars.len() || chars[i] != '=' {
+    return Err(ParseError::new(source.trim(), "a formula starting with `='").from_column(i + 1));
+  }
+  i += 1;
+
+  let mut tokens = vec![];
+  while i < chars.len() {
+    let c = chars[i];
+    let start = i;
+
+    if c.is_whitespace() {
+      i += 1;
+    } else if c.is_ascii_digit() {
+      while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') { i += 1; }
+      tokens.push((start + 1, Token::Number(chars[start..i].iter().collect())));
+    } else if c.is_ascii_alphabetic() {
+      while i < chars.len() && chars[i].is_ascii_alphabetic() { i += 1; }
+      let letters: String = chars[start..i].iter().collect();
+      let digits_start = i;
+      while i < chars.len() && chars[i].is_ascii_digit() { i += 1; }
+
+      if i == digits_start {
+        tokens.push((start + 1, Token::Name(letters)));
+        continue;
+      }
+
+      let text: String = chars[start..i].iter().collect();
+      let digits: String = chars[digits_start..i].iter().collect();
+      let row = digits.parse::<usize>().ok().and_then(|r| r.checked_sub(1));
+      match (row, Address::column_number(&letters)) {
+        (Some(row), Some(col)) => tokens.push((start + 1, Token::Cell(Address::new(row, col)))),
+        _ => return Err(ParseError::new(&text, "a cell like `B2'").from_column(start + 1))
+      }
+    } else if "+-*/(),:".contains(c) {
+      tokens.push((start + 1, Token::Symbol(c)));
+      i += 1;
+    } else {
+      return Err(ParseError::new(&c.to_string(), "a number, cell, function, or one of + - * / ( ) , :").from_column(start + 1));
+    }
+  }
+
+  return Ok(tokens);
+}
+
+// Recursive descent, loosest first: sums, then products, then negation, then everything else.
+// How deeply a formula's brackets, functions and operators can nest. Parsing, working out and
+// dropping a formula all recurse through it, so without a limit a long enough one would
+// overflow the stack.
+const MAX_NESTING: usize = 100;
+
+struct FormulaParser<T> {
+  tokens: Vec<(usize, Token)>,
+  pos: usize,
+  // the brackets, functions and minus signs being parsed inside of
+  open: usize,
+  // the column just past the end, for errors about running out
+  end: usize,
+  numbers: PhantomData<T>
+}
+
+impl<T: Number> FormulaParser<T> {
+  fn parse(mut self) -> Result<Expr<T>, ParseError> {
+    let (expr, _) = self.sum()?;
+    if self.pos < self.tokens.len() {
+      return Err(self.unexpected("an operator or the end of the formula"));
+    }
+    return Ok(expr);
+  }
+
+  fn peek(&self) -> Option<&Token> {
+    return self.tokens.get(self.pos).map(|(_, t)| t);
+  }
+
+  fn next_is(&self, symbol: char) -> bool {
+    return self.peek() == Some(&Token::Symbol(symbol));
+  }
+
+  fn expect(&mut self, symbol: char) -> Result<(), ParseError> {
+    if !self.next_is(symbol) {
+      return Err(self.unexpected(&format!("`{}'", symbol)));
+    }
+    self.pos += 1;
+    return Ok(());
+  }
+
+  fn unexpected(&self, expected: &str) -> ParseError {
+    match self.tokens.get(self.pos) {
+      Some(&(column, ref token)) => {
+        let text = match *token {
+          Token::Number(ref n) => n.to_owned(),
+          Token::Cell(a) => a.to_string(),
+          Token::Name(ref n) => n.to_owned(),
+          Token::Symbol(c) => c.to_string()
+        };
+        return ParseError::new(&text, expected).from_column(column);
+      },
+      None => return ParseError::new("", expected).from_column(self.end)
+    }
+  }
+
+  // Each part of the formula comes with how deeply it nests, so a formula can be turned down
+  // before it goes past `MAX_NESTING`, whether by brackets or a long run of operators.
+  fn sum(&mut self) -> Result<(Expr<T>, usize), ParseError> {
+    let (mut expr, mut depth) = self.product()?;
+    loop {
+      let op = if self.next_is('+') { BinaryOp::Add } else if self.next_is('-') { BinaryOp::Sub } else { return Ok((expr, depth)) };
+      self.pos += 1;
+      let (right, d) = self.product()?;
+      depth = self.deeper(depth.max(d))?;
+      expr = Expr::Binary(op, Box::new(expr), Box::new(right));
+    }
+  }
+
+  fn product(&mut self) -> Result<(Expr<T>, usize), ParseError> {
+    let (mut expr, mut depth) = self.unary()?;
+    loop {
+      let op = if self.next_is('*') { BinaryOp::Mul } else if self.next_is('/') { BinaryOp::Div } else { return Ok((expr, depth)) };
+      self.pos += 1;
+      let (right, d) = self.unary()?;
+      depth = self.deeper(depth.max(d))?;
+      expr = Expr::Binary(op, Box::new(expr), Box::new(right));
+    }
+  }
+
+  fn unary(&mut self) -> Result<(Expr<T>, usize), ParseError> {
+    if self.next_is('-') {
+      self.open()?;
+      self.pos += 1;
+      let (expr, depth) = self.unary()?;
+      self.open -= 1;
+      return Ok((Expr::Neg(Box::new(expr)), self.deeper(depth)?));
+    }
+    return self.primary();
+  }
+
+  // one level further in, or an error at the next token if that's too far
+  fn open(&mut self) -> Result<(), ParseError> {
+    self.deeper(self.open)?;
+    self.open += 1;
+    return Ok(());
+  }
+
+  fn deeper(&self, depth: usize) -> Result<usize, ParseError> {
+    if depth >= MAX_NESTING {
+      return Err(self.unexpected(&format!("a formula nested at most {} deep", MAX_NESTING)));
+    }
+    return Ok(depth + 1);
+  }
+
+  fn primary(&mut self) -> Result<(Expr<T>, usize), ParseError> {
+    let token = self.peek().cloned();
+    match token {
+      Some(Token::Number(ref n)) => {
+        let column = self.tokens[self.pos].0;
+        let n = T::parse(n).map_err(|e| e.from_column(column))?;
+        self.pos += 1;
+        return Ok((Expr::Number(n), 0));
+      },
+      Some(Token::Cell(a)) => {
+        self.pos += 1;
+        return Ok((Expr::Ref(a), 0));
+      },
+      Some(Token::Symbol('(')) => {
+        self.open()?;
+        self.pos += 1;
+        let parsed = self.sum()?;
+        self.expect(')')?;
+        self.open -= 1;
+        return Ok(parsed);
+      },
+      Some(Token::Name(ref name)) => {
+        let function = match name.to_ascii_uppercase().as_str() {
+          "SUM" => Function::Sum,
+          "MIN" => Function::Min,
+          "MAX" => Function::Max,
+          _ => return Err(self.unexpected("one of SUM, MIN or MAX"))
+        };
+        self.open()?;
+        self.pos += 1;
+        self.expect('(')?;
+
+        let mut args = vec![];
+        let mut depth = 0;
+        if !self.next_is(')') {
+          loop {
+            let (arg, d) = self.arg()?;
+            args.push(arg);
+            depth = depth.max(d);
+            if !self.next_is(',') { break; }
+            self.pos += 1;
+          }
+        }
+        self.expect(')')?;
+        self.open -= 1;
+
+        return Ok((Expr::Call(function, args), self.deeper(depth)?));
+      },
+      _ => return Err(self.unexpected("a number, cell, function or `('"))
+    }
+  }
+
+  fn arg(&mut self) -> Result<(Arg<T>, usize), ParseError> {
+    let token = self.peek().cloned();
+    let following = self.tokens.get(self.pos + 1).map(|(_, t)| t.clone());
+
+    match (token, following) {
+      (Some(Token::Name(ref name)), Some(Token::Number(ref row))) if name.eq_ignore_ascii_case("row") => {
+        self.pos += 1;
+        match row.parse::<usize>().ok().and_then(|r| r.checked_sub(1)) {
+          Some(row) => {
+            self.pos += 1;
+            return Ok((Arg::Row(row), 0));
+          },
+          None => return Err(self.unexpected("a row number from 1"))
+        }
+      },
+      (Some(Token::Name(ref name)), Some(Token::Name(ref letters))) if name.eq_ignore_ascii_case("col") => {
+        self.pos += 1;
+        match Address::column_number(letters) {
+          Some(col) => {
+            self.pos += 1;
+            return Ok((Arg::Col(col), 0));
+          },
+          None => return Err(self.unexpected("a column like `B'"))
+        }
+      },
+      (Some(Token::Cell(from)), Some(Token::Symbol(':'))) => {
+        self.pos += 2;
+        match self.peek().cloned() {
+          Some(Token::Cell(to)) => {
+            self.pos += 1;
+            return Ok((Arg::Range(from, to), 0));
+          },
+          _ => return Err(self.unexpected("a cell to end the range"))
+        }
+      },
+      _ => {
+        let (expr, depth) = self.sum()?;
+        return Ok((Arg::Value(expr), depth));
+      }
+    }
+  }
+}
+
+// Works out formulas on demand, remembering each one so it's only worked out once.
+struct Evaluator<'a, T: 'a> {
+  sheet: &'a Spreadsheet<T>,
+  values: HashMap<Address, T>,
+  // the formulas part way through being worked out, innermost last, with the cells each refers
+  // to and how many of those have been looked at
+  evaluating: Vec<(Address, Vec<Address>, usize)>,
+  // where each of those is in `evaluating`
+  depth: HashMap<Address, usize>
+}
+
+impl<'a, T: Number> Evaluator<'a, T> {
+  fn new(sheet: &'a Spreadsheet<T>) -> Evaluator<'a, T> {
+    return Evaluator { sheet: sheet, values: HashMap::new(), evaluating: vec![], depth: HashMap::new() };
+  }
+
+  // A depth-first walk down the references, kept on `evaluating` rather than the call stack so a
+  // long chain of formulas can't overflow it. A formula is worked out once everything it refers
+  // to has been.
+  fn value(&mut self, at: Address) -> Result<T, FormulaError> {
+    if let Some(v) = self.known(at, at)? {
+      return Ok(v);
+    }
+    self.start(at);
+
+    while let Some(frame) = self.evaluating.last_mut() {
+      let (current, next) = (frame.0, frame.1.get(frame.2).cloned());
+      frame.2 += 1;
+
+      match next {
+        Some(r) => {
+          if self.known(r, current)?.is_some() {
+            continue;
+          }
+          if let Some(&idx) = self.depth.get(&r) {
+            let mut path: Vec<Address> = self.evaluating[idx..].iter().map(|e| e.0).collect();
+            path.push(r);
+            return Err(FormulaError::Cycle(path));
+          }
+          self.start(r);
+        },
+        None => {
+          let formula = self.sheet.cell(current).and_then(|c| c.formula.as_ref()).expect("only formulas are evaluated");
+          let v = self.eval(&formula.expr, current)?;
+          self.values.insert(current, v);
+          self.evaluating.pop();
+          self.depth.remove(&current);
+        }
+      }
+    }
+
+    return Ok(self.values[&at].clone());
+  }
+
+  // the value at a cell `from` refers to if it's a number or a formula already worked out, `None`
+  // for a formula still to do
+  fn known(&self, at: Address, from: Address) -> Result<Option<T>, FormulaError> {
+    if let Some(v) = self.values.get(&at) {
+      return Ok(Some(v.clone()));
+    }
+    match self.sheet.cell(at) {
+      None => return Err(FormulaError::NoSuchCell { at: from, missing: at }),
+      Some(cell) if cell.formula.is_none() => return Ok(Some(cell.value())),
+      Some(_) => return Ok(None)
+    }
+  }
+
+  fn start(&mut self, at: Address) {
+    let formula = self.sheet.cell(at).and_then(|c| c.formula.as_ref()).expect("only formulas are evaluated");
+    let mut refs = vec![];
+    self.references(&formula.expr, &mut refs);
+    self.depth.insert(at, self.evaluating.len());
+    self.evaluating.push((at, refs, 0));
+  }
+
+  // every cell the expression refers to, in the order working it out reaches them
+  fn references(&self, expr: &Expr<T>, found: &mut Vec<Address>) {
+    match *expr {
+      Expr::Number(_) => (),
+      Expr::Ref(a) => found.push(a),
+      Expr::Neg(ref e) => self.references(e, found),
+      Expr::Binary(_, ref l, ref r) => {
+        self.references(l, found);
+        self.references(r, found);
+      },
+      Expr::Call(_, ref args) => {
+        for arg in args {
+          match *arg {
+            Arg::Value(ref e) => self.references(e, found),
+            _ => found.extend(self.sheet.expand(arg))
+          }
+        }
+      }
+    }
+  }
+
+  fn eval(&self, expr: &Expr<T>, at: Address) -> Result<T, FormulaError> {
+    match *expr {
+      Expr::Number(ref n) => return Ok(n.clone()),
+      Expr::Ref(a) => return Ok(self.known(a, at)?.expect("references are worked out first")),
+      Expr::Neg(ref e) => return self.eval(e, at)?.checked_neg().ok_or(FormulaError::Overflow(at)),
+      Expr::Binary(op, ref l, ref r) => {
+        let l = self.eval(l, at)?;
+        let r = self.eval(r, at)?;
+        let result = match op {
+          BinaryOp::Add => l.checked_add(&r),
+          BinaryOp::Sub => l.checked_sub(&r),
+          BinaryOp::Mul => l.checked_mul(&r),
+          BinaryOp::Div if r.is_zero() => return Err(FormulaError::DivideByZero(at)),
+          BinaryOp::Div => l.checked_div(&r)
+        };
+        return result.ok_or(FormulaError::Overflow(at));
+      },
+      Expr::Call(function, ref args) => {
+        let mut values = vec![];
+        for arg in args {
+          match *arg {
+            Arg::Value(ref e) => values.push(self.eval(e, at)?),
+            _ => {
+              for a in self.sheet.expand(arg) {
+                values.push(self.known(a, at)?.expect("references are worked out first"));
+              }
+            }
+          }
+        }
+
+        match function {
+          Function::Sum => return values.iter().try_fold(T::zero(), |acc, v| acc.checked_add(v)).ok_or(FormulaError::Overflow(at)),
+          Function::Min => return values.into_iter().reduce(|m, v| if v < m { v } else { m }).ok_or(FormulaError::NoValues(at)),
+          Function::Max => return values.into_iter().reduce(|m, v| if v > m { v } else { m }).ok_or(FormulaError::NoValues(at))
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod spreadsheet_tests {
+  use super::*;
+
+  #[test]
+  fn test_spreadsheet_new_error_1() {
+    let e = Spreadsheet::<i32>::new(String::from("dummy data goes here\nanother dummy row here")).unwrap_err();
+    assert_eq!((e.line, e.column), (1, 1));
+    assert_eq!(e.text, "dummy");
+  }
+
+  #[test]
+  fn test_spreadsheet_new_error_2() {
+    // bad delimiter
+    let e = Spreadsheet::<i32>::new(String::from("1 2 3 4 5\n1 2 3 4_5")).unwrap_err();
+    assert_eq!((e.line, e.column), (2, 7));
+    assert_eq!(e.text, "4_5");
+    assert_eq!(e.expected, "a value of type i32");
+  }
+
+  #[test]
+  fn test_spreadsheet_new_happy_heterogenous_row_length() {
+    let sr = Spreadsheet::new(String::from("5 40 2 8 1\n6 41 3 9 2\n3 3 4 4")).unwrap();
+    assert_eq!(sr[0][0], SheetCell::from(5));
+    assert_eq!(sr[0][1], SheetCell::from(40));
+    assert_eq!(sr[0][2], SheetCell::from(2));
+    assert_eq!(sr[0][3], SheetCell::from(8));
+    assert_eq!(sr[0][4], SheetCell::from(1));
+    assert_eq!(sr[1][0], SheetCell::from(6));
+    assert_eq!(sr[1][1], SheetCell::from(41));
+    assert_eq!(sr[1][2], SheetCell::from(3));
+    assert_eq!(sr[1][3], SheetCell::from(9));
+    assert_eq!(sr[1][4], SheetCell::from(2));
+    assert_eq!(sr[2][0], SheetCell::from(3));
+    assert_eq!(sr[2][1], SheetCell::from(3));
+    assert_eq!(sr[2][2], SheetCell::from(4));
+    assert_eq!(sr[2][3], SheetCell::from(4));
+  }
+
+  #[test]
+  fn test_spreadsheet_new_happy_uniform_row_length() {
+    let sr = Spreadsheet::new(String::from("5 40 2 8 1\n6 41 3 9 2")).unwrap();
+    assert_eq!(sr[0][0], SheetCell::from(5));
+    assert_eq!(sr[0][1], SheetCell::from(40));
+    assert_eq!(sr[0][2], SheetCell::from(2));
+    assert_eq!(sr[0][3], SheetCell::from(8));
+    assert_eq!(sr[0][4], SheetCell::from(1));
+    assert_eq!(sr[1][0], SheetCell::from(6));
+    assert_eq!(sr[1][1], SheetCell::from(41));
+    assert_eq!(sr[1][2], SheetCell::from(3));
+    assert_eq!(sr[1][3], SheetCell::from(9));
+    assert_eq!(sr[1][4], SheetCell::from(2));
+  }
+
+  #[test]
+  fn test_spreadsheet_indexing_happy() {
+    let sr = Spreadsheet::new(String::from("5\n1 2")).unwrap();
+    assert_eq!(sr[0][0], SheetCell::from(5));
+    assert_eq!(sr[1][1], SheetCell::from(2));
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_spreadsheet_indexing_out_of_bounds_panics() {
+    let sr = Spreadsheet::new(String::from("5")).unwrap();
+    assert_eq!(sr[0][10], SheetCell::from(0));
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_spreadsheet_indexing_out_of_bounds_panics_on_rows() {
+    let sr = Spreadsheet::<i32>::new(String::from("5")).unwrap();
+    assert_eq!(sr[10][10], sr[12][12]);
+  }
+}
+
+#[cfg(test)]
+mod import_tests {
+  use super::*;
+
+  fn values(sheet: &Spreadsheet) -> Vec<Vec<i32>> {
+    return sheet.rows.iter().map(|r| r.cells.iter().map(|c| c.data).collect()).collect();
+  }
+
+  #[test]
+  fn split_quoted_and_padded() {
+    let fields = split_fields(" 1, \"2\" ,\"3\"\"\",", Delimiter::Comma).unwrap();
+    assert_eq!(fields, vec![(2, String::from("1")), (5, String::from("2")), (10, String::from("3\"")), (16, String::new())]);
+
+    let fields = split_fields("1\t\t 2", Delimiter::Tab).unwrap();
+    assert_eq!(fields, vec![(1, String::from("1")), (3, String::new()), (5, String::from("2"))]);
+
+    let fields = split_fields("  5 \"9\"\t2 ", Delimiter::Whitespace).unwrap();
+    assert_eq!(fields, vec![(3, String::from("5")), (5, String::from("9")), (9, String::from("2"))]);
+  }
+
+  #[test]
+  fn split_malformed_quotes() {
+    let e = split_fields("1,\"2", Delimiter::Comma).unwrap_err();
+    assert_eq!((e.column, e.text.as_str(), e.expected.as_str()), (3, "\"2", "a closing `\"'"));
+
+    let e = split_fields("\"1\"x,2", Delimiter::Comma).unwrap_err();
+    assert_eq!(e.column, 4);
+  }
+
+  #[test]
+  fn import_csv_with_header_and_comments() {
+    let data = "# exported\nmin,max,\"the rest\"\n\n5,1,9\n\"7\",5,3\n";
+    let sheet = Spreadsheet::import(data, &Format::csv().with_header()).unwrap();
+    assert_eq!(sheet.headers(), &["min", "max", "the rest"]);
+    assert_eq!(values(&sheet), vec![vec![5, 1, 9], vec![7, 5, 3]]);
+    assert_eq!(sheet.part1(), Ok(12));
+  }
+
+  #[test]
+  fn import_tsv() {
+    let sheet = Spreadsheet::import("5\t9\t2\t8\n9\t4\t7\t3\n", &Format::tsv()).unwrap();
+    assert_eq!(sheet.part2(), Ok(7));
+    assert!(sheet.headers().is_empty());
+  }
+
+  #[test]
+  fn import_reports_where() {
+    let e = Spreadsheet::<i32>::import("a,b\n1,2\n3,x\n", &Format::csv().with_header()).unwrap_err();
+    assert_eq!((e.line, e.column), (3, 3));
+
+    let e = Spreadsheet::<i32>::import("1,,2", &Format::csv()).unwrap_err();
+    assert_eq!((e.column, e.text.as_str()), (3, ""));
+  }
+
+  #[test]
+  fn detect() {
+    assert_eq!(Format::detect("1, 2\n3, 4").delimiter, Delimiter::Comma);
+    assert_eq!(Format::detect("1\t2\n3 4").delimiter, Delimiter::Whitespace);
+    assert_eq!(Day2.parse("5,1,9,5\n7,5,3").unwrap().part1(), Ok(12));
+  }
+
+  #[test]
+  fn detect_skips_comments() {
+    let data = "# exported 2017, by hand\n\n5 1 9 5\n7 5 3\n";
+    assert_eq!(Format::detect(data).delimiter, Delimiter::Whitespace);
+    assert_eq!(Day2.parse(data).unwrap().part1(), Ok(12));
+  }
+
+  #[test]
+  fn import_past_a_byte_order_mark() {
+    let data = "\u{feff}5,1,9,5\n7,5,3\n";
+    assert_eq!(Format::detect(data).delimiter, Delimiter::Comma);
+    assert_eq!(values(&Spreadsheet::import(data, &Format::csv()).unwrap()), vec![vec![5, 1, 9, 5], vec![7, 5, 3]]);
+    assert_eq!(Day2.parse("\u{feff}5 1 9 5\n7 5 3").unwrap().part1(), Ok(12));
+
+    // columns count from after it
+    let e = Spreadsheet::<i32>::import("\u{feff}1,x", &Format::csv()).unwrap_err();
+    assert_eq!(e.column, 3);
+  }
+}
+
+#[cfg(test)]
+mod sheet_row_tests {
+  use super::*;
+
+  #[test]
+  fn test_sheetrow_part1() {
+    let sr = SheetRow::new(String::from("1 2 3 4 5")).unwrap();
+    assert_eq!(sr.part1(), Ok(4));
+  }
+
+  #[test]
+  fn test_sheetrow_part2_ex1() {
+    let sr = SheetRow::new(String::from("5 9 2 8")).unwrap();
+    assert_eq!(sr.part2(), Ok(4));
+  }
+
+  #[test]
+  fn test_sheetrow_part2_ex2() {
+    let sr = SheetRow::new(String::from("9 4 7 3")).unwrap();
+    assert_eq!(sr.part2(), Ok(3));
+  }
+
+  #[test]
+  fn test_sheetrow_part2_ex3() {
+    let sr = SheetRow::new(String::from("3 8 6 5")).unwrap();
+    assert_eq!(sr.part2(), Ok(2));
+  }
+
+  #[test]
+  fn test_sheetrow_rowmax() {
+    let sr = SheetRow::<i32>::new(String::from("1 2 3 4 5")).unwrap();
+    assert_eq!(sr.row_max(), 5);
+  }
+
+  #[test]
+  fn test_sheetrow_rowmin() {
+    let sr = SheetRow::<i32>::new(String::from("1 2 3 4 5")).unwrap();
+    assert_eq!(sr.row_min(), 1);
+  }
+
+  #[test]
+  fn test_sheetrow_len() {
+    let sr = SheetRow::<i32>::new(String::from("1 2 3 4 5")).unwrap();
+    assert_eq!(sr.len(), 5);
+  }
+
+  #[test]
+  fn test_sheetrow_new_error_1() {
+    assert!(SheetRow::<i32>::new(String::from("dummy data goes here")).is_err());
+  }
+
+  #[test]
+  fn test_sheetrow_new_error_2() {
+    // bad delimiter
+    let e = SheetRow::<i32>::new(String::from("1 2  3 4_5")).unwrap_err();
+    assert_eq!(e.column, 8);
+  }
+
+  #[test]
+  fn test_sheetrow_new_happy() {
+    let sr = SheetRow::new(String::from("5 40 2 8 1")).unwrap();
+    assert_eq!(sr[0], SheetCell::from(5));
+    assert_eq!(sr[1], SheetCell::from(40));
+    assert_eq!(sr[2], SheetCell::from(2));
+    assert_eq!(sr[3], SheetCell::from(8));
+    assert_eq!(sr[4], SheetCell::from(1));
+  }
+
+  #[test]
+  fn test_sheetrow_indexing_happy() {
+    let sr = SheetRow::new(String::from("5")).unwrap();
+    assert_eq!(sr[0], SheetCell::from(5));
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_sheetrow_indexing_out_of_bounds_panics() {
+    let sr = SheetRow::new(String::from("5")).unwrap();
+    assert_eq!(sr[10], SheetCell::from(0));
+  }
+}
+
+#[cfg(test)]
+mod addressing_tests {
+  use super::*;
+
+  fn sheet() -> Spreadsheet {
+    return Spreadsheet::new(String::from("5 1 9 5\n7 5 3\n2 4 6 8")).unwrap();
+  }
+
+  fn values<'a, I: Iterator<Item = &'a SheetCell>>(cells: I) -> Vec<i32> {
+    return cells.map(|c| c.value()).collect();
+  }
+
+  #[test]
+  fn two_dimensional() {
+    let s = sheet();
+    assert_eq!(s[(1, 2)], SheetCell::from(3));
+    assert_eq!(s[Address::new(2, 3)], SheetCell::from(8));
+    assert_eq!(s["B3"], SheetCell::from(4));
+    assert_eq!(s["d1"], SheetCell::from(5));
+    assert_eq!(s.at("D2"), None);
+    assert_eq!(s.at("2D"), None);
+  }
+
+  #[test]
+  #[should_panic(expected = "D2 out of bounds")]
+  fn past_a_short_row() {
+    let _ = &sheet()[(1, 3)];
+  }
+
+  #[test]
+  #[should_panic(expected = "3 out of bounds")]
+  fn one_row_past_the_end() {
+    let _ = &sheet()[3];
+  }
+
+  #[test]
+  fn parse_addresses() {
+    assert_eq!(Address::parse("AB10"), Ok(Address::new(9, 27)));
+    assert_eq!(Address::parse(" a1 "), Ok(Address::new(0, 0)));
+    for bad in ["A0", "A", "1", "A1B", "A-1", ""].iter() {
+      assert!(Address::parse(bad).is_err(), "{}", bad);
+    }
+  }
+
+  #[test]
+  fn columns() {
+    let s = sheet();
+    assert_eq!(s.width(), 4);
+    assert_eq!(values(s.column(1)), vec![1, 5, 4]);
+    assert_eq!(values(s.column(3)), vec![5, 8]);
+    assert_eq!(s.columns().count(), 4);
+    assert_eq!(values(s.rows().map(|r| &r[0])), vec![5, 7, 2]);
+  }
+
+  #[test]
+  fn slices() {
+    let s = sheet();
+    let block = s.slice(1..3, 1..10);
+    assert_eq!(block, Spreadsheet::new(String::from("5 3\n4 6 8")).unwrap());
+    assert!(s.slice(5..9, 0..2).is_empty());
+    // columns a short row doesn't reach leave it empty, which has no range
+    assert_eq!(s.slice(0..3, 3..10).part1(), Err(ChecksumError { row: 1, error: RowError::Empty }));
+  }
+
+  #[test]
+  fn transpose_and_column_checksums() {
+    let s = sheet();
+    let t = s.transpose();
+    assert_eq!(t, Spreadsheet::new(String::from("5 7 2\n1 5 4\n9 3 6\n5 8")).unwrap());
+    assert_eq!(t.checksum(&Range), Ok(5 + 4 + 6 + 3));
+    // a rectangular sheet comes back to itself, a ragged one needn't
+    let square = s.slice(0..3, 0..3);
+    assert_eq!(square.transpose().transpose(), square);
+  }
+}
+
+#[cfg(test)]
+mod number_tests {
+  use super::*;
+
+  #[test]
+  fn past_i32() {
+    let data = "3000000000 7 1500000000\n5 10 4000000001\n";
+    let sheet: Spreadsheet<i64> = Spreadsheet::import(data, &Format::whitespace()).unwrap();
+    assert_eq!(sheet.part1(), Ok(2999999993 + 3999999996));
+    assert_eq!(sheet.part2(), Ok(2 + 2));
+    assert_eq!(Day2.part1(&Day2.parse(data).unwrap()), Answer::Number(6999999989));
+    assert!(Spreadsheet::<i32>::new(String::from(data)).is_err());
+  }
+
+  #[test]
+  fn overflow() {
+    let row = SheetRow::<i32>::new(String::from("2147483647 -1")).unwrap();
+    assert_eq!(row.part1(), Err(RowError::Overflow));
+    assert_eq!(Sum.checksum(&row), Ok(2147483646));
+
+    let sheet = Spreadsheet::<i32>::new(String::from("1 2\n0 2147483647\n0 1")).unwrap();
+    let e = ChecksumError { row: 1, error: RowError::Overflow };
+    assert_eq!(sheet.part1(), Err(e));
+    assert_eq!(e.to_string(), "row 2: the checksum overflows");
+
+    let e = Spreadsheet::<i32>::import("2147483647,=A1+1", &Format::csv()).unwrap().computed().unwrap_err();
+    assert_eq!(e, FormulaError::Overflow(Address::new(0, 1)));
+  }
+
+  #[test]
+  fn unsigned() {
+    let sheet = Spreadsheet::<u64>::new(String::from("5 9 2 8\n9 4 7 3\n3 8 6 5")).unwrap();
+    assert_eq!(sheet.part1(), Ok(18));
+    assert_eq!(sheet.part2(), Ok(9));
+    assert_eq!(sheet.checksum(&Median), Ok(6 + 5 + 5));
+    assert!(Spreadsheet::<u64>::new(String::from("1 -2")).is_err());
+    assert_eq!(Spreadsheet::<u64>::import("1,=A1-2", &Format::csv()).unwrap().computed(), Err(FormulaError::Overflow(Address::new(0, 1))));
+  }
+
+  #[test]
+  fn floats() {
+    let sheet = Spreadsheet::<f64>::new(String::from("1.5 0.25 3\n-1 2.5 7")).unwrap();
+    assert_eq!(sheet[0].row_max(), 3.0);
+    assert_eq!(sheet[1].row_min(), -1.0);
+    assert_eq!(sheet.part1(), Ok(2.75 + 8.0));
+    assert_eq!(sheet[0].part2(), Ok(6.0));
+    assert_eq!(Median.checksum(&SheetRow::<f64>::new(String::from("1 2")).unwrap()), Ok(1.5));
+
+    let formulas = Spreadsheet::<f64>::import("3,=A1*1.5,=B1/4", &Format::csv()).unwrap();
+    assert_eq!(formulas.evaluate(Address::new(0, 2)), Ok(1.125));
+
+    let e = SheetCell::<f64>::new(String::from("inf")).unwrap_err();
+    assert_eq!(e.expected, "a finite number");
+    assert!(SheetCell::<i32>::new(String::from("=1.5")).is_err());
+  }
+
+  #[test]
+  fn big_integers() {
+    let data = "123456789012345678901234567890 10 3\n7 98765432109876543210 70";
+    let sheet = Spreadsheet::<BigInt>::new(String::from(data)).unwrap();
+    let expected: BigInt = "123456789012345678901234567887".parse::<BigInt>().unwrap() + "98765432109876543203".parse::<BigInt>().unwrap();
+    assert_eq!(sheet.part1(), Ok(expected));
+    assert_eq!(sheet[1].part2(), Ok(BigInt::from(10)));
+
+    let unsigned = Spreadsheet::<BigUint>::new(String::from(data)).unwrap();
+    assert_eq!(unsigned.checksum(&Modular { modulus: BigUint::from(1000u32) }), Ok(BigUint::from(903u32 + 287)));
+  }
+}
+
+#[cfg(test)]
+mod division_tests {
+  use super::*;
+  use generate::Rng;
+
+  fn row<T: Number>(data: &str) -> SheetRow<T> {
+    return SheetRow::new(String::from(data)).unwrap();
+  }
+
+  fn pair(dividend: usize, divisor: usize) -> Pair {
+    return Pair { dividend: dividend, divisor: divisor };
+  }
+
+  // every pair checked, for comparison
+  fn brute_force(r: &SheetRow<i64>) -> Vec<Pair> {
+    let mut pairs = vec![];
+    for i in 0..r.len() {
+      for j in 0..r.len() {
+        if i != j && r[j].value() != 0 && r[i].value() % r[j].value() == 0 {
+          pairs.push(Pair::of(r, i, j));
+        }
+      }
+    }
+    pairs.sort();
+    pairs.dedup();
+    return pairs;
+  }
+
+  #[test]
+  fn unique_ambiguous_and_missing() {
+    assert_eq!(row::<i32>("5 9 2 8").divisibility(), Divisibility::Unique(pair(3, 2)));
+    assert_eq!(row::<i32>("2 4 8").divisibility(), Divisibility::Ambiguous(vec![pair(1, 0), pair(2, 0), pair(2, 1)]));
+    assert_eq!(row::<i32>("3 5 7").divisibility(), Divisibility::Missing);
+    assert_eq!(row::<i32>("").divisibility(), Divisibility::Missing);
+  }
+
+  #[test]
+  fn checksum_without_a_pair() {
+    assert_eq!(row::<i32>("3 5 7").part2(), Err(RowError::NoDivisiblePair));
+    let sheet = Spreadsheet::<i32>::new(String::from("5 9 2 8\n3 5 7")).unwrap();
+    assert_eq!(sheet.part2(), Err(ChecksumError { row: 1, error: RowError::NoDivisiblePair }));
+    assert_eq!(sheet.ambiguous_rows(), vec![(1, Divisibility::Missing)]);
+  }
+
+  #[test]
+  fn zeros() {
+    // anything but 0 goes into 0, and 0 goes into nothing
+    assert_eq!(row::<i32>("0 5 7").divisible_pairs(), vec![pair(0, 1), pair(0, 2)]);
+    assert_eq!(row::<i32>("0 0").divisibility(), Divisibility::Missing);
+    assert_eq!(row::<i32>("7 0 3").part2(), Ok(0));
+  }
+
+  #[test]
+  fn signs_and_sizes() {
+    assert_eq!(row::<i32>("-4 4 3").divisible_pairs(), vec![pair(1, 0)]);
+    assert_eq!(row::<i32>("-4 4 3").part2(), Ok(-1));
+    assert_eq!(row::<i32>("-9 5 3").part2(), Ok(-3));
+    assert_eq!(row::<i32>("-2147483648 -1").divisible_pairs(), vec![pair(0, 1)]);
+    assert_eq!(row::<i32>("-2147483648 -1").part2(), Err(RowError::Overflow));
+    assert_eq!(row::<u64>("18446744073709551615 5 7").part2(), Ok(3689348814741910323));
+  }
+
+  #[test]
+  fn floats() {
+    assert_eq!(row::<f64>("1.5 4.5 2").divisible_pairs(), vec![pair(1, 0)]);
+    assert_eq!(row::<f64>("1.5 4.5 2").part2(), Ok(3.0));
+  }
+
+  #[test]
+  fn same_as_checking_every_pair() {
+    let mut rng = Rng::new(2);
+    for _ in 0..200 {
+      // small values look divisors up, large ones in narrow rows fall back to checking each cell
+      let (width, size) = (rng.range(0, 40), if rng.range(0, 1) == 0 { 60 } else { 1 << 40 });
+      let values: Vec<String> = (0..width).map(|_| rng.range(-size, size).to_string()).collect();
+      let r = row::<i64>(&values.join(" "));
+      assert_eq!(r.divisible_pairs(), brute_force(&r), "{}", values.join(" "));
+    }
+
+    // either side of where three cells stop looking divisors up, at 4² = 16
+    for values in ["16 2 8", "15 3 5", "-16 -4 1", "17 1 17"].iter() {
+      let r = row::<i64>(values);
+      assert_eq!(r.divisible_pairs(), brute_force(&r), "{}", values);
+    }
+  }
+
+  #[test]
+  fn warnings() {
+    assert_eq!(Day2.warnings("5 9 2 8\n2 4 8\n3 5 7"), vec![
+      "row 2: 3 divisible pairs (B2/A2, C2/A2, C2/B2), part 2 uses the first",
+      "row 3: no two values divide evenly"
+    ]);
+    assert!(Day2.warnings("5 9 2 8\n9 4 7 3").is_empty());
+  }
+
+  #[test]
+  fn rows_without_a_pair_are_unsolvable() {
+    use solution::Solver;
+
+    let unsolvable = |why: &str| Err(SolveError::Unsolvable(String::from(why)));
+    assert_eq!(Day2.solve(2, "5 9 2 8\n3 5 7\n"), unsolvable("row 2: no two values divide evenly"));
+    assert_eq!(Day2.solve(2, "0 0\n"), unsolvable("row 1: no two values divide evenly"));
+    // part 1 doesn't need the pairs
+    assert_eq!(Day2.solve(1, "5 9 2 8\n3 5 7\n"), Ok(Answer::Number(11)));
+    assert_eq!(part2(String::from("3 5 7")).unwrap_err().to_string(), "row 1: no two values divide evenly");
+  }
+}
+
+#[cfg(test)]
+mod export_tests {
+  use super::*;
+
+  fn sheet() -> Spreadsheet {
+    return Spreadsheet::import("a,\"b, c\",c,d\n5,9,2,8\n9,4,7,3\n3,8,6\n", &Format::csv().with_header()).unwrap();
+  }
+
+  fn both<'a>(export: Export<'a, i32>) -> Export<'a, i32> {
+    return export.with_checksum("range", &Range).with_checksum("division", &EvenDivision);
+  }
+
+  #[test]
+  fn text() {
+    let plain: Spreadsheet = Spreadsheet::new(String::from("5 9 2 8\n9 4 7 3\n3 8 6 5")).unwrap();
+    assert_eq!(Export::text().with_checksum("range", &Range).render(&plain).unwrap(), concat!(
+      "row    A  B  C  D  range\n",
+      "1      5  9  2  8      7\n",
+      "2      9  4  7  3      6\n",
+      "3      3  8  6  5      5\n",
+      "total                 18\n"
+    ));
+
+    assert_eq!(both(Export::text()).with_highlights().render(&sheet()).unwrap(), concat!(
+      "row      a  b, c    c    d  range  division\n",
+      "1       5    [9]  [2]  [8]      7         4\n",
+      "2      [9]    4    7   [3]      6         3\n",
+      "3      [3]   [8]  [6]           5         2\n",
+      "total                          18         9\n"
+    ));
+  }
+
+  #[test]
+  fn csv() {
+    assert_eq!(both(Export::csv()).render(&sheet()).unwrap(), concat!(
+      "row,a,\"b, c\",c,d,range,division\n",
+      "1,5,9,2,8,7,4\n",
+      "2,9,4,7,3,6,3\n",
+      "3,3,8,6,,5,2\n",
+      "total,,,,,18,9\n"
+    ));
+
+    // the cells stay numbers, with where the checksums came from alongside
+    let highlighted = both(Export::csv()).with_highlights().render(&sheet()).unwrap();
+    assert_eq!(highlighted.lines().next(), Some("row,a,\"b, c\",c,d,range,range cells,division,division cells"));
+    assert_eq!(highlighted.lines().nth(1), Some("1,5,9,2,8,7,B1 C1,4,C1 D1"));
+  }
+
+  #[test]
+  fn markdown() {
+    assert_eq!(both(Export::markdown()).with_highlights().render(&sheet()).unwrap(), concat!(
+      "| row | a | b, c | c | d | range | division |\n",
+      "| --- | ---: | ---: | ---: | ---: | ---: | ---: |\n",
+      "| 1 | 5 | **9** | **2** | **8** | 7 | 4 |\n",
+      "| 2 | **9** | 4 | 7 | **3** | 6 | 3 |\n",
+      "| 3 | **3** | **8** | **6** |  | 5 | 2 |\n",
+      "| **total** |  |  |  |  | **18** | **9** |\n"
+    ));
+  }
+
+  #[test]
+  fn contributors() {
+    let row = SheetRow::<i32>::new(String::from("5 1 9 5 1")).unwrap();
+    assert_eq!(Range.contributors(&row), vec![1, 2]);
+    assert_eq!(Median.contributors(&row), vec![0]);
+    assert_eq!(Median.contributors(&SheetRow::<i32>::new(String::from("4 1 3 2")).unwrap()), vec![2, 3]);
+    assert_eq!(EvenDivision.contributors(&row), vec![0, 1]);
+    assert!(Sum.contributors(&row).is_empty());
+  }
+
+  #[test]
+  fn checksum_errors() {
+    let sheet = Spreadsheet::<i32>::new(String::from("5 9 2 8\n3 5 7")).unwrap();
+    let e = Export::text().with_checksum("division", &EvenDivision).render(&sheet).unwrap_err();
+    assert_eq!(e, ChecksumError { row: 1, error: RowError::NoDivisiblePair });
+  }
+}
+
+#[cfg(test)]
+mod checksum_tests {
+  use super::*;
+
+  fn sheet() -> Spreadsheet {
+    return Spreadsheet::new(String::from("5 9 2 8\n9 4 7 3\n3 8 6 5")).unwrap();
+  }
+
+  #[test]
+  fn built_ins() {
+    let s = sheet();
+    assert_eq!(s.checksum(&Range), Ok(7 + 6 + 5));
+    assert_eq!(s.checksum(&EvenDivision), Ok(4 + 3 + 2));
+    assert_eq!(s.checksum(&Sum), Ok(24 + 23 + 22));
+    assert_eq!(s.checksum(&Median), Ok(6 + 5 + 5));
+    assert_eq!(s.checksum(&Modular { modulus: 10 }), Ok(4 + 3 + 2));
+  }
+
+  #[test]
+  fn median_and_modular_edges() {
+    assert_eq!(Median.checksum(&SheetRow::new(String::from("7 -1 3")).unwrap()), Ok(3));
+    assert_eq!(Median.checksum(&SheetRow::new(String::from("-4 1")).unwrap()), Ok(-2));
+    assert_eq!(Median.checksum(&SheetRow::new(String::new()).unwrap()), Ok(0));
+    assert_eq!(Modular { modulus: 7 }.checksum(&SheetRow::new(String::from("-3 1")).unwrap()), Ok(5));
+    assert_eq!(Modular { modulus: 0 }.checksum(&SheetRow::new(String::from("-3 1")).unwrap()), Err(RowError::ZeroModulus));
+    assert_eq!(sheet().checksum(&Modular { modulus: 0 }), Err(ChecksumError { row: 0, error: RowError::ZeroModulus }));
+    assert_eq!(Range.checksum(&SheetRow::<i32>::new(String::new()).unwrap()), Err(RowError::Empty));
+    assert_eq!(Spreadsheet::<i32>::new(String::from("1 2\n\n3 4")).unwrap().part1(), Err(ChecksumError { row: 1, error: RowError::Empty }));
+  }
+
+  #[test]
+  fn user_supplied() {
+    // how many cells are even
+    let evens = |row: &SheetRow| row.iter().filter(|c| c.value() % 2 == 0).count() as i32;
+    assert_eq!(sheet().checksum(&evens), Ok(2 + 1 + 2));
+
+    let boxed: Box<dyn RowChecksum> = Box::new(Range);
+    assert_eq!(sheet().checksum(&*boxed), Ok(18));
+  }
+}
+
+#[cfg(test)]
+mod formula_tests {
+  use super::*;
+
+  fn csv(data: &str) -> Spreadsheet {
+    return Spreadsheet::import(data, &Format::csv()).unwrap();
+  }
+
+  fn a1(row: usize, col: usize) -> Address {
+    return Address::new(row, col);
+  }
+
+  #[test]
+  fn addresses() {
+    let names: Vec<String> = [0, 1, 25, 26, 27, 701, 702].iter().map(|&c| Address::column_name(c)).collect();
+    assert_eq!(names, vec!["A", "B", "Z", "AA", "AB", "ZZ", "AAA"]);
+    assert_eq!(Address::column_number("aab"), Some(703));
+    assert_eq!(a1(9, 27).to_string(), "AB10");
+  }
+
+  #[test]
+  fn arithmetic() {
+    let s = csv("2,3,4,=A1+B1*C1,=-(A1-B1*C1)/2,= 7 / 2 - -1");
+    assert_eq!(s.evaluate(a1(0, 3)), Ok(14));
+    assert_eq!(s.evaluate(a1(0, 4)), Ok(5));
+    assert_eq!(s.evaluate(a1(0, 5)), Ok(4));
+  }
+
+  #[test]
+  fn functions_over_runs() {
+    // a formula with a comma in it has to be quoted
+    let s = csv("1,2,3\n4,5,6\n=SUM(A1:C2),\"=max(row 2, 10)\",=MIN(col B)");
+    assert_eq!(s.evaluate(a1(2, 0)), Ok(21));
+    assert_eq!(s.evaluate(a1(2, 1)), Ok(10));
+    assert_eq!(s.evaluate(a1(2, 2)), Ok(2));
+    assert_eq!(s.evaluate(a1(0, 0)), Ok(1));
+  }
+
+  #[test]
+  fn formulas_of_formulas() {
+    let s = csv("=B1*2,=C1+1,5\n=SUM(row 1)");
+    assert_eq!(s.evaluate(a1(1, 0)), Ok(12 + 6 + 5));
+    assert_eq!(s.dependencies(a1(1, 0)), vec![a1(0, 0), a1(0, 1), a1(0, 2)]);
+    assert_eq!(s.dependents(a1(0, 1)), vec![a1(0, 0), a1(1, 0)]);
+    assert_eq!(s.dependencies(a1(0, 2)), vec![]);
+  }
+
+  #[test]
+  fn only_what_is_needed_is_worked_out() {
+    // C1 is broken, but nothing A1 needs
+    let s = csv("=B1+1,2,=C1");
+    assert_eq!(s.evaluate(a1(0, 0)), Ok(3));
+    assert_eq!(s.evaluate(a1(0, 2)), Err(FormulaError::Cycle(vec![a1(0, 2), a1(0, 2)])));
+  }
+
+  #[test]
+  fn cycles_and_missing_cells() {
+    let e = csv("=B1,=C1,=A1").evaluate(a1(0, 0)).unwrap_err();
+    assert_eq!(e, FormulaError::Cycle(vec![a1(0, 0), a1(0, 1), a1(0, 2), a1(0, 0)]));
+    assert_eq!(e.to_string(), "cycle A1 -> B1 -> C1 -> A1");
+    assert_eq!(csv("=B1,=C1").evaluate(a1(0, 0)), Err(FormulaError::NoSuchCell { at: a1(0, 1), missing: a1(0, 2) }));
+  }
+
+  #[test]
+  fn long_chains_of_formulas() {
+    // each row refers to the one below, far deeper than recursing would get in a debug build
+    let rows = 50_000;
+    let mut data: Vec<String> = (2..(rows + 1)).map(|r| format!("=A{}", r)).collect();
+    data.push(String::from("7"));
+    let s = csv(&data.join("\n"));
+    assert_eq!(s.evaluate(a1(0, 0)), Ok(7));
+
+    // and back round to the top
+    data[rows - 1] = String::from("=A1+1");
+    let e = csv(&data.join("\n")).evaluate(a1(0, 0)).unwrap_err();
+    match e {
+      FormulaError::Cycle(ref path) => assert_eq!(path.len(), rows + 1),
+      _ => panic!("expected a cycle, not {}", e)
+    }
+  }
+
+  #[test]
+  fn deeply_nested_formulas() {
+    let nested = |formula: String| Day2.parse(&format!("1,={}", formula));
+    assert_eq!(nested(format!("{}1", "-".repeat(99))).unwrap().part1(), Ok(2));
+    assert_eq!(nested(format!("{}1{}", "(".repeat(99), ")".repeat(99))).unwrap().part1(), Ok(0));
+
+    // brackets, functions, minus signs and runs of operators all count, and are turned down long
+    // before the stack would run out
+    for formula in [
+      format!("{}1", "-".repeat(50_000)),
+      format!("{}1{}", "(".repeat(200_000), ")".repeat(200_000)),
+      format!("{}A1{}", "SUM(".repeat(10_000), ")".repeat(10_000)),
+      format!("1{}", "+1".repeat(50_000))
+    ].iter() {
+      let e = nested(formula.clone()).unwrap_err();
+      assert_eq!((e.line, e.expected.as_str()), (1, "a formula nested at most 100 deep"));
+    }
+  }
+
+  #[test]
+  fn evaluation_errors() {
+    let s = csv("0,=5/A1,=2147483647+1,=MAX(D9:D10),=SUM()");
+    assert_eq!(s.evaluate(a1(0, 1)), Err(FormulaError::DivideByZero(a1(0, 1))));
+    assert_eq!(s.evaluate(a1(0, 2)), Err(FormulaError::Overflow(a1(0, 2))));
+    assert_eq!(s.evaluate(a1(0, 3)), Err(FormulaError::NoSuchCell { at: a1(0, 3), missing: a1(8, 3) }));
+    assert_eq!(s.evaluate(a1(0, 4)), Ok(0));
+    assert_eq!(csv("=MIN(row 9)").evaluate(a1(0, 0)), Err(FormulaError::NoValues(a1(0, 0))));
+  }
+
+  #[test]
+  fn ranges_past_the_sheet() {
+    // a range far larger than the sheet stops at its first missing cell rather than listing them all
+    let s = csv("1,=SUM(A1:A300000000)\n2");
+    assert_eq!(s.evaluate(a1(0, 1)), Err(FormulaError::NoSuchCell { at: a1(0, 1), missing: a1(2, 0) }));
+    let s = csv("1,2,3\n4,5,=MAX(ZZZ300000000:A1)");
+    assert_eq!(s.evaluate(a1(1, 2)), Err(FormulaError::NoSuchCell { at: a1(1, 2), missing: a1(0, 3) }));
+    assert!(Day2.parse("1,=SUM(A1:A300000000)").is_err());
+  }
+
+  #[test]
+  fn malformed_formulas() {
+    let e = Spreadsheet::<i32>::import("1, =A1+", &Format::csv()).unwrap_err();
+    assert_eq!((e.column, e.expected.as_str()), (8, "a number, cell, function or `('"));
+
+    let e = Spreadsheet::<i32>::import("1\n2,=FOO(1)", &Format::csv()).unwrap_err();
+    assert_eq!((e.line, e.column, e.text.as_str()), (2, 4, "FOO"));
+    assert_eq!(e.expected, "one of SUM, MIN or MAX");
+
+    let e = SheetCell::<i32>::new(String::from("=A0")).unwrap_err();
+    assert_eq!(e.expected, "a cell like `B2'");
+    assert!(SheetCell::<i32>::new(String::from("=SUM(A1:)")).is_err());
+    assert!(SheetCell::<i32>::new(String::from("=(1")).is_err());
+    assert!(SheetCell::<i32>::new(String::from("=1 2")).is_err());
+    assert!(SheetCell::<i32>::new(String::from("=1 & 2")).is_err());
+  }
+
+  #[test]
+  fn checksums_over_computed_sheets() {
+    let s = csv("5,1,9,=A1\n7,5,3\n2,4,6,8");
+    assert_eq!(s.computed().unwrap().part1(), Ok(8 + 4 + 6));
+    assert_eq!(s.computed().unwrap().headers(), s.headers());
+
+    assert_eq!(Day2.parse("5,1,=C2+1,5\n7,5,8").unwrap().part1(), Ok(8 + 3));
+  }
+
+  #[test]
+  fn day_reports_where_a_formula_went_wrong() {
+    let e = Day2.parse("1,2\n3, =B2\n").unwrap_err();
+    assert_eq!((e.line, e.column, e.text.as_str()), (2, 4, "=B2"));
+    assert_eq!(e.expected, "a formula that doesn't depend on itself (cycle B2 -> B2)");
+  }
+}
+
+#[cfg(test)]
+mod sheet_cell_tests {
+  use super::*;
+
+  #[test]
+  fn test_sheetcell_new_error() {
+    let e = SheetCell::<i32>::new(String::from("dummy")).unwrap_err();
+    assert_eq!(e.text, "dummy");
+  }
+
+  #[test]
+  fn test_sheetcell_new_happy() {
+    let sc = SheetCell::<i32>::new(String::from("1")).unwrap();
+    assert_eq!(sc.data, 1);
+  }
+
+  #[test]
+  fn test_sheetcell_from_happy() {
+    let sc = SheetCell::from(1);
+    assert_eq!(sc.data, 1);
+  }
+}
+
+#[cfg(test)]
+mod advent_example_tests {
+  use super::*;
+  use input::read_day_file;
+
+  #[test]
+  fn test_part1_ex1() {
+    let test_content = read_day_file(2, "tests/part1_ex1");
+
+    assert_eq!(part1(test_content), Ok(18));
+  }
+
+  #[test]
+  fn test_part2_ex1() {
+    let test_content = read_day_file(2, "tests/part2_ex1");
+
+    assert_eq!(part2(test_content), Ok(9));
+  }
+}
@@ -0,0 +1,1298 @@
+/* /////////////////////////////////////////////////////////
+
+   First, notice that the side of each successive ring around the center is equal
+   to the `2n+1`, starting at `n=0`.
+
+   Next, notice that drawing a line starting at 1 and proceeding down and to the
+   right one square is always equal to `(2n+1)^2`. We can work out this relative
+   difference for the whole grid like this:
+
+   -----
+
+   Let N(n) = (2n+1)^2
+
+         N(n-1) + 4n              N(n-1) + 3n                   N(n-1) + 2n
+
+                       \               |                    /
+
+                          N(0) + 4   N(0) + 3    N(0) + 2
+
+         N(n) - 3n  ...   N(1) - 3   N(0)        N(0) + 1  ...  N(n-1)  + n
+
+                          N(1) - 2   N(1) - 1    N(1)      -> increment `n` and repeat
+
+                       /               |                    \   < this contains 2n - 1 elements (half the bar), not including the center or corner>
+
+         N(n) - 2n                 N(n) - n                     N(n)
+
+
+
+   Using this, and the fact that routing _from_ the target _to_ the origin is the
+   same as routing _from_ the origin _to_ the target, all we need to establish is:
+
+   1. What the relative shift left/right of the center the value is, call it `LR`
+   2. What the shift above/below the center we are, call it `UD`
+
+   The Manhattan distance is just `LR + UD`.
+
+   However, one of those values will actually just be the ring value. If the target
+   is on a horizontal 'bar' of the ring, then the UD value will be the ring value,
+   and the LR value will be it's absolute shift off the centerline; if it's on the
+   vertical 'bar', then vice versa. Fortunately, once we know the ring #, it's very
+   easy to exploit the structure of the spiral to figure out the rest.
+
+   We know the length of a side is `n`, so we can start by determining how far the
+   target is from the nearest perfect square (or any other corner). Our target
+   (given in `day3/input` is: 368078
+
+   It's square root is: 606.69, so the next highest square is `607^2 = 368449`
+
+   This indicates it's in ring `2n + 1 = 607 => 303`
+
+   To determine which row it is in, we can take the difference, `368449 - 368078 =
+   371` The bars of that ring is 607 values wide, so this indicates it's on the
+   left side of ring 303. So `UD = ring = 303`. Additionally, since `371 <
+   607`, we know we're on the bottom bar (we would need to be `~3*607` to end up
+   walking around to the top bar.
+
+   Since the center of the bottom bar is `N(n) - n`, we can take the absolute
+   difference of this from our target to get `LR`, it should be less than half the
+   bar length (for obvious reasons):
+
+   607^2 - 303 = 368146; 368146 - 368078 = 68
+
+   So we're relatively close to the centerline, `LR = 68`,
+
+   Therefore, the Manhattan distance is `LR + UD = 303 + 68 = 371` -- the fact that
+   this is equal to the difference is a coincidence, I believe.
+
+*/ /////////////////////////////////////////////////////////
+
+/* /////////////////////////////////////////////////////////
+
+   Part 2 is a little tougher. We need to distill that weird function into
+   something a bit more contained.
+
+   The complications come in two parts. First, every piece is now
+   self-referential, it is the sum of the squares around it, and notably it's
+   only filled with values from the square around it that _are already
+   filled_.
+
+   The second is that we're looking for the first value that's _larger_ than
+   our target (the same as before).
+
+   Here's an approach, though. Let's assume that this sequence is recurrent
+   polynomial, that is, it is definied by a recurrence relation that is
+   polynomial over `n`, the index of recurrence, and it's function, in
+   notation:
+
+       M(n) = An^k + An^k-1 + ... + BM(n)^j + ...
+
+   With this assumption, we can generate a few example values, and then look
+   at their discrete derivatives to try to suss out a pattern. Here's a chunk
+   of the spiral I pulled from the example (with a few extra entries filled in:
+
+
+    147 142 133 122  59
+    304   5   4   2  57
+    330  10   1   1  54
+    351  11  23  25  26
+    362 747 806 854 905  931
+
+   The sequence, `M(n)` is:
+
+      1, 1, 54, 57, 59, 122, 133, 142, 147, 304, 330, 351, 362, 747, 806, 854, 905, 931
+
+   The first discrete derivative is:
+
+      D(M,n) = M(n) - M(n-1) = {X, 0, 53, 3, 2, 63, 11, 9, 5, 157, 21 11, 385, 59, 48, 51, 26}
+
+   The second discrete derivative is:
+
+      D(D(M,n),n) = {X, X, 53, -50, -1, ...
+
+   This is unlikely to be fruitful. I think this is where we dump out to
+   computing to solve the problem.
+
+   There might be an interesting approach where we regard the spiral as a
+   graph, and then maybe we can exploit some pattern in the graph structure to
+   make the thing easier to compute, but in any case, the datastructure for
+   this is pretty natural -- just a list of coordinates and their values,
+   along with a function to 'populate' the next value in the spiral. We need
+   to know the function to map from the one-dimensional index to the
+   two-dimensional position on the graph, we can use the work from above to
+   help with that. Each ring `n` starts with a lowest value of  `N(n-1) + n`
+   and has a maximum value of  `N(n) + 2n - 1`, i.e., each ring contains:
+
+        (N(n) + 2n - 1) - (N(n-1) + n) = (2n+1)^2 + 2n - 1 - (2(n-1) + 1)^2 - n)
+                                       = 9n - 1
+
+   (note that this applies to all but the 0th ring, which has 1 element).
+
+   Since each corner of the ring contains `2n + 1` elements, we simply need to 
+   travel up `2n` elements (including the corner), left `2n`, down `2n`, and
+   right `2n` before incrementing `n` and repeating. The natural datastructure
+   here is a sparse matrix.
+
+*/ /////////////////////////////////////////////////////////
+
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+use grid::{Direction, Point};
+use observe::Observer;
+use parsing::{parse_as, ParseError};
+use solution::{Answer, Solution, SolveError};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+  type Input = i64;
+
+  fn day(&self) -> u8 { return 3; }
+  fn title(&self) -> &'static str { return "Spiral Memory"; }
+
+  // the square to route from, the spiral starts at 1
+  fn parse(&self, input: &str) -> Result<i64, ParseError> {
+    let target = parse_as::<i64>(input)?;
+    if target < 1 {
+      return Err(ParseError::new(input.trim(), "a square of at least 1"));
+    }
+    return Ok(target);
+  }
+
+  fn part1(&self, input: &i64) -> Answer {
+    return Answer::Number(part1(*input));
+  }
+
+  // targets at or past the largest value that fits have no answer
+  fn try_part2(&self, input: &i64) -> Result<Answer, SolveError> {
+    match part2(*input) {
+      Ok(value) => return Ok(Answer::Number(value)),
+      Err(e) => return Err(SolveError::Unsolvable(format!("{}, so nothing written is larger than {}", e, input)))
+    }
+  }
+}
+
+// the Manhattan distance from the square back to 1, see above
+pub fn part1(target: i64) -> i64 {
+  let p = SparseMatrix::spiral_to_point(target);
+  return p.x.abs() + p.y.abs();
+}
+
+// the shortest way back from `from` to the origin: along the row to the middle column, then
+// down or up it, both ends included
+pub fn route(from: Point) -> Vec<Point> {
+  let mut p = from;
+  let mut points = vec![p];
+  while p != Point::new(0, 0) {
+    let dir = if p.x > 0 {
+      Direction::Left
+    } else if p.x < 0 {
+      Direction::Right
+    } else if p.y > 0 {
+      Direction::Down
+    } else {
+      Direction::Up
+    };
+    p = p.step(dir);
+    points.push(p);
+  }
+  return points;
+}
+
+// the first value written larger than the target, or where the values stop fitting in an i64 if
+// it's at least as large as all of those
+pub fn part2(target: i64) -> Result<i64, Overflow> {
+  let mut sm = SparseMatrix::new();
+  loop {
+    let value = sm.try_tip()?;
+    if value > target {
+      return Ok(value);
+    }
+  }
+}
+
+// The stress test's next value, whatever its fill rule makes of its neighbours, doesn't fit in
+// an i64.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Overflow {
+  pub square: i64
+}
+
+impl fmt::Display for Overflow {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "the value for square {} overflows an i64", self.square)
+  }
+}
+
+impl Error for Overflow {}
+
+// one step: the next square along the spiral gets its value from the fill rule
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Fill {
+  pub square: i64,
+  pub point: Point,
+  pub value: i64
+}
+
+// Which of a square's neighbours a built-in rule looks at.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Neighbourhood {
+  // the 4 squares sharing a side
+  Adjacent,
+  // all 8, diagonals included, as the puzzle has it
+  Surrounding
+}
+
+impl Neighbourhood {
+  pub fn of(&self, p: Point) -> Vec<Point> {
+    match *self {
+      Neighbourhood::Adjacent => return p.adjacent(),
+      Neighbourhood::Surrounding => return p.neighborhood(1)
+    }
+  }
+}
+
+// How the next square's value is worked out from the values already written around it. Only
+// the neighbours that have been filled are passed on, in the order `neighbours` gives them.
+pub trait FillRule {
+  fn neighbours(&self, p: Point) -> Vec<Point> {
+    return p.neighborhood(1);
+  }
+
+  // `None` if the value doesn't fit in an i64
+  fn fill(&self, square: i64, point: Point, values: &[i64]) -> Option<i64>;
+}
+
+// Any closure over the square's index and its filled 8-neighbourhood is a rule.
+impl<F: Fn(i64, &[i64]) -> Option<i64>> FillRule for F {
+  fn fill(&self, square: i64, _point: Point, values: &[i64]) -> Option<i64> {
+    return self(square, values);
+  }
+}
+
+// the puzzle's stress test
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Sum(pub Neighbourhood);
+
+impl FillRule for Sum {
+  fn neighbours(&self, p: Point) -> Vec<Point> { return self.0.of(p); }
+
+  fn fill(&self, _square: i64, _point: Point, values: &[i64]) -> Option<i64> {
+    return values.iter().try_fold(0i64, |acc, v| acc.checked_add(*v));
+  }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Product(pub Neighbourhood);
+
+impl FillRule for Product {
+  fn neighbours(&self, p: Point) -> Vec<Point> { return self.0.of(p); }
+
+  fn fill(&self, _square: i64, _point: Point, values: &[i64]) -> Option<i64> {
+    return values.iter().try_fold(1i64, |acc, v| acc.checked_mul(*v));
+  }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Max(pub Neighbourhood);
+
+impl FillRule for Max {
+  fn neighbours(&self, p: Point) -> Vec<Point> { return self.0.of(p); }
+
+  // every square past the first has a filled neighbour, the one before it
+  fn fill(&self, _square: i64, _point: Point, values: &[i64]) -> Option<i64> {
+    return Some(values.iter().cloned().max().unwrap_or(0));
+  }
+}
+
+#[derive(PartialEq, Eq)]
+pub struct SparseMatrix<R = Sum> {
+  spiral_pointer: i64,
+  coordinates: HashMap<Point, i64>,
+  rule: R,
+  spiral: Spiral
+}
+
+impl SparseMatrix {
+  // the stress test: each square the sum of its filled 8-neighbourhood, starting from 1
+  pub fn new() -> SparseMatrix {
+    return SparseMatrix::with_rule(Sum(Neighbourhood::Surrounding), 1);
+  }
+}
+
+impl<R: FillRule> SparseMatrix<R> {
+  // a spiral filled by `rule`, with `origin` written in square 1
+  pub fn with_rule(rule: R, origin: i64) -> SparseMatrix<R> {
+    let mut sm = SparseMatrix {
+      // indexes at 1, start at 2, we'll manually populate the origin in a
+      // moment
+      spiral_pointer: 2,
+      coordinates: HashMap::new(),
+      rule: rule,
+      spiral: Spiral::new()
+    };
+
+    sm.set(Point::new(0,0), origin);
+
+    return sm;
+  }
+
+  // fill along `spiral` instead of the puzzle's, squares numbered from its first index
+  pub fn along(mut self, spiral: Spiral) -> SparseMatrix<R> {
+    assert!(self.coordinates.len() == 1, "a matrix can only change spirals before it's filled");
+    self.spiral_pointer = spiral.first + 1;
+    self.spiral = spiral;
+    return self;
+  }
+
+  pub fn tip(&mut self) -> i64 {
+    let p = self.pointer();
+    return self.get(p);
+  }
+
+  // as `tip`, but an error rather than a panic once the values overflow
+  pub fn try_tip(&mut self) -> Result<i64, Overflow> {
+    let p = self.pointer();
+    while !self.has(p) {
+      self.try_populate_observed(&mut ())?;
+    }
+    return Ok(self.coordinates[&p]);
+  }
+
+  pub fn set(&mut self, p: Point, v: i64) {
+    self.coordinates.insert(p,v);
+  }
+
+  // the squares out to ring `size`, as far as they've been filled
+  pub fn dump(&self, size: i64) {
+    println!("---------------------------");
+    print!("{}", Picture::ascii().with_size(size).render(self));
+    println!("---------------------------");
+  }
+
+  pub fn get(&mut self, p: Point) -> i64{
+    return self.get_observed(p, &mut ());
+  }
+
+  // as `get`, with every square filled on the way there observed
+  pub fn get_observed<O: Observer<SparseMatrix<R>, Fill>>(&mut self, p: Point, observer: &mut O) -> i64 {
+    while !self.has(p) {
+      self.populate_observed(observer);
+    }
+    //println!("DEBUG#get: coordinates = {:?}", self.coordinates);
+    return *self.coordinates.get(&p).expect("");
+  }
+
+  // the value at `p` if it's been filled, without filling it
+  pub fn peek(&self, p: Point) -> Option<i64> {
+    return self.coordinates.get(&p).cloned();
+  }
+
+  pub fn has(&self, p: Point) -> bool {
+    let attempt = self.coordinates.get(&p);
+    //println!("DEBUG#has: attempt= {:?}", attempt);
+    match attempt {
+      Some(_) => return true,
+      None => return false
+    }
+  }
+
+  // don't need this yet
+  //pub fn extent(&self) -> i64 {
+    //let p = SparseMatrix::spiral_to_point(self.spiral_pointer);
+    //if p.x.abs() > p.y.abs() {
+      //return p.x.abs();
+    //} else {
+      //return p.y.abs();
+    //}
+  //}
+
+  pub fn pointer(&self) -> Point {
+    return self.spiral.point(self.spiral_pointer);
+  }
+
+  pub fn populate(&mut self) {
+    self.populate_observed(&mut ());
+  }
+
+  pub fn populate_observed<O: Observer<SparseMatrix<R>, Fill>>(&mut self, observer: &mut O) {
+    if let Err(e) = self.try_populate_observed(observer) {
+      panic!("{}", e);
+    }
+  }
+
+  // as `populate_observed`, leaving the matrix as it was if the next value overflows
+  pub fn try_populate_observed<O: Observer<SparseMatrix<R>, Fill>>(&mut self, observer: &mut O) -> Result<(), Overflow> {
+    let point = self.pointer();
+    let values: Vec<i64> = self.rule.neighbours(point).into_iter()
+      .filter_map(|n| self.coordinates.get(&n).cloned())
+      .collect();
+    let value = self.rule.fill(self.spiral_pointer, point, &values)
+      .ok_or(Overflow { square: self.spiral_pointer })?;
+
+    let fill = Fill { square: self.spiral_pointer, point: point, value: value };
+    observer.before(self, &fill);
+    self.set(fill.point, value);
+    self.spiral_pointer += 1;
+    observer.after(self, &fill);
+    return Ok(());
+  }
+}
+
+impl SparseMatrix {
+  pub fn spiral_to_point(idx: i64) -> Point {
+    assert!(idx >= 1, "the spiral starts at square 1, not {}", idx);
+
+    // Worked in i128: the outermost rings' corners don't fit in an i64 even though the squares
+    // on them do.
+    let idx = idx as i128;
+
+    // the ring in which the index resides
+    let mut ring = ceil_sqrt(idx);
+    // we want the perfect square on the down-left diagonal
+    if ring % 2 == 0 { ring += 1; }
+
+    let n = (ring - 1) / 2; // this is the index of the ring, we'll need it later
+
+    let x: i128 ; let y: i128;
+
+    let bottom_left_corner = (2*n + 1).pow(2) - 2*n;
+    let top_left_corner = (2*n - 1).pow(2) + 4*n;
+    let top_right_corner = (2*n - 1).pow(2) + 2*n;
+    let bottom_right_corner = (2*n -1).pow(2);
+
+    if idx > bottom_left_corner {
+      let bottom_axis = (2*n + 1).pow(2) - n;
+      // if idx > center, then this is negative (and we're to the left of
+      // the y-axis, otherwise we're positive and to the right).
+      x = idx - bottom_axis;
+      // we're left the y-axis
+      y = -n;
+    } else if idx == bottom_left_corner {
+      x = -n;
+      y = -n;
+    } else if idx > top_left_corner {
+      let left_axis = (2*n + 1).pow(2) - 3*n;
+      // below the x axis
+      x = -n;
+      y = left_axis -idx;
+    } else if idx == top_left_corner {
+      x = -n;
+      y = n;
+    } else if idx > top_right_corner {
+      let top_axis = (2*n - 1).pow(2) + 3*n;
+      x = top_axis - idx;
+      // we're right of the y-axis
+      y = n;
+    } else if idx == top_right_corner {
+      x = n;
+      y = n;
+    } else if idx == bottom_right_corner {
+      x = n;
+      y = -n;
+    } else { // we're on the right bar
+      let right_axis = (2*n - 1).pow(2) + n;
+      // we're above the x-axis
+      x = n;
+      y = idx - right_axis;
+    }
+
+    // within the ring, so as small as the index's square root
+    return Point::new(x as i64, y as i64);
+
+  }
+
+  // The inverse of `spiral_to_point`: the square at a point, worked out from the ring the point
+  // is on and which side of it, without walking the spiral. Each side is one of the axis
+  // formulas above, solved for the index.
+  pub fn point_to_spiral(p: Point) -> i64 {
+    match SparseMatrix::checked_point_to_spiral(p) {
+      Some(idx) => return idx,
+      None => panic!("the square at {:?} is past the largest i64", p)
+    }
+  }
+
+  // as `point_to_spiral`, `None` for the points on rings so far out their squares don't fit in
+  // an i64, from about 1.5 billion out
+  pub fn checked_point_to_spiral(p: Point) -> Option<i64> {
+    let (x, y) = (p.x as i128, p.y as i128);
+    let n = x.abs().max(y.abs());
+    if n == 0 { return Some(1); }
+    // far enough out that even the ring's first square is too large, and i128 would overflow too
+    if n > u32::MAX as i128 { return None; }
+
+    let idx = if x == n && y > -n {
+      // the right bar, up from just above the previous ring's last square
+      (2*n - 1).pow(2) + n + y
+    } else if y == n {
+      // the top, leftwards
+      (2*n - 1).pow(2) + 3*n - x
+    } else if x == -n {
+      // the left bar, downwards
+      (2*n + 1).pow(2) - 3*n - y
+    } else {
+      // the bottom, rightwards, finishing the ring on `(2n+1)^2`
+      (2*n + 1).pow(2) - n + x
+    };
+    return i64::try_from(idx).ok();
+  }
+}
+
+// Which way a spiral turns at its corners.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Rotation {
+  Clockwise,
+  CounterClockwise
+}
+
+// A square spiral's layout: the puzzle's turns counter-clockwise, heads right from the origin
+// first and numbers the origin 1. Every other layout is the puzzle's mirrored and turned, so
+// `point` and `index` are `spiral_to_point` and `point_to_spiral` either side of that.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Spiral {
+  pub rotation: Rotation,
+  // from the origin to the next square
+  pub start: Direction,
+  // the origin's index
+  pub first: i64
+}
+
+impl Spiral {
+  pub fn new() -> Spiral {
+    return Spiral { rotation: Rotation::CounterClockwise, start: Direction::Right, first: 1 };
+  }
+
+  pub fn with_rotation(mut self, rotation: Rotation) -> Spiral {
+    self.rotation = rotation;
+    return self;
+  }
+
+  pub fn with_start(mut self, start: Direction) -> Spiral {
+    self.start = start;
+    return self;
+  }
+
+  pub fn with_first(mut self, first: i64) -> Spiral {
+    self.first = first;
+    return self;
+  }
+
+  pub fn point(&self, idx: i64) -> Point {
+    assert!(idx >= self.first, "the spiral starts at square {}, not {}", self.first, idx);
+    match i64::try_from(idx as i128 - self.first as i128 + 1) {
+      Ok(square) => return self.orient(SparseMatrix::spiral_to_point(square)),
+      Err(_) => panic!("square {} is past the largest i64 squares from {}", idx, self.first)
+    }
+  }
+
+  pub fn index(&self, p: Point) -> i64 {
+    match self.checked_index(p) {
+      Some(idx) => return idx,
+      None => panic!("the square at {:?} is past the largest i64", p)
+    }
+  }
+
+  // as `index`, `None` when the point's square doesn't fit in an i64
+  pub fn checked_index(&self, p: Point) -> Option<i64> {
+    let square = SparseMatrix::checked_point_to_spiral(self.unorient(p)?)?;
+    return i64::try_from(square as i128 + self.first as i128 - 1).ok();
+  }
+
+  pub fn iter(&self) -> SpiralIter {
+    return SpiralIter { spiral: *self, next: Some((self.first, Point::new(0, 0))) };
+  }
+
+  // from the puzzle's spiral to this one: mirror, then turn the first step round to `start`
+  fn orient(&self, p: Point) -> Point {
+    let y = match self.rotation {
+      Rotation::CounterClockwise => p.y,
+      Rotation::Clockwise => -p.y
+    };
+    match self.start {
+      Direction::Right => return Point::new(p.x, y),
+      Direction::Up => return Point::new(-y, p.x),
+      Direction::Left => return Point::new(-p.x, -y),
+      Direction::Down => return Point::new(y, -p.x)
+    }
+  }
+
+  // `orient` undone, `None` for the coordinates that can't be negated
+  fn unorient(&self, p: Point) -> Option<Point> {
+    let (x, y) = match self.start {
+      Direction::Right => (p.x, p.y),
+      Direction::Up => (p.y, p.x.checked_neg()?),
+      Direction::Left => (p.x.checked_neg()?, p.y.checked_neg()?),
+      Direction::Down => (p.y.checked_neg()?, p.x)
+    };
+    match self.rotation {
+      Rotation::CounterClockwise => return Some(Point::new(x, y)),
+      Rotation::Clockwise => return Some(Point::new(x, y.checked_neg()?))
+    }
+  }
+}
+
+// Every `(index, point)` along a spiral in turn, stepping from one square to the next rather
+// than working each out afresh. Ends after the square numbered `i64::MAX`.
+pub struct SpiralIter {
+  spiral: Spiral,
+  // the index, and the point on the puzzle's spiral
+  next: Option<(i64, Point)>
+}
+
+impl Iterator for SpiralIter {
+  type Item = (i64, Point);
+
+  fn next(&mut self) -> Option<(i64, Point)> {
+    let (idx, p) = self.next?;
+    self.next = idx.checked_add(1).map(|i| (i, p.step(SpiralIter::heading(p))));
+    return Some((idx, self.spiral.orient(p)));
+  }
+}
+
+impl SpiralIter {
+  // which way the puzzle's spiral goes from `p`
+  fn heading(p: Point) -> Direction {
+    let n = p.x.abs().max(p.y.abs());
+    if p.x == n && p.y == -n {
+      // the ring's last square, or the origin: out onto the next ring
+      return Direction::Right;
+    } else if p.x == n && p.y < n {
+      return Direction::Up;
+    } else if p.y == n && p.x > -n {
+      return Direction::Left;
+    } else if p.x == -n && p.y > -n {
+      return Direction::Down;
+    } else {
+      return Direction::Right;
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Style {
+  // columns as wide as the widest value, for reading in a terminal
+  Ascii,
+  Svg
+}
+
+// A matrix drawn square by square out to a ring, `size`, with `.` for squares not filled yet.
+// Without a size it's just large enough for everything filled and the route. Drawing never fills
+// anything, so it shows a fill rule's progress as it stands.
+//
+// A route highlights the squares on `route` from one square back to the origin: `[5]` in ASCII,
+// and shaded with a line along it in SVG.
+pub struct Picture {
+  pub style: Style,
+  pub size: Option<i64>,
+  pub route: Option<i64>
+}
+
+impl Picture {
+  pub fn ascii() -> Picture {
+    return Picture::new(Style::Ascii);
+  }
+
+  pub fn svg() -> Picture {
+    return Picture::new(Style::Svg);
+  }
+
+  pub fn new(style: Style) -> Picture {
+    return Picture { style: style, size: None, route: None };
+  }
+
+  pub fn with_size(self, size: i64) -> Picture {
+    return Picture { size: Some(size), ..self };
+  }
+
+  // the route from `square`, numbered along the matrix's spiral
+  pub fn with_route(self, square: i64) -> Picture {
+    return Picture { route: Some(square), ..self };
+  }
+
+  pub fn render<R: FillRule>(&self, sm: &SparseMatrix<R>) -> String {
+    let path = match self.route {
+      Some(square) => route(sm.spiral.point(square)),
+      None => vec![]
+    };
+    let ring = |p: &Point| p.x.abs().max(p.y.abs());
+    let size = self.size.unwrap_or_else(|| {
+      return sm.coordinates.keys().chain(path.iter()).map(ring).max().unwrap_or(0);
+    });
+
+    let on_route: HashSet<Point> = path.iter().cloned().collect();
+
+    // top row first
+    let mut rows = vec![];
+    for y in (-size..(size + 1)).rev() {
+      let mut row = vec![];
+      for x in -size..(size + 1) {
+        let p = Point::new(x, y);
+        let text = sm.peek(p).map(|v| v.to_string()).unwrap_or_else(|| String::from("."));
+        row.push((text, on_route.contains(&p)));
+      }
+      rows.push(row);
+    }
+    let width = rows.iter().flat_map(|r| r.iter()).map(|c| c.0.len()).max().unwrap_or(1);
+
+    match self.style {
+      Style::Ascii => return ascii_grid(&rows, width),
+      Style::Svg => return svg_grid(&rows, width, size, &path)
+    }
+  }
+}
+
+fn ascii_grid(rows: &[Vec<(String, bool)>], width: usize) -> String {
+  let mut out = String::new();
+  for row in rows {
+    let mut line = String::new();
+    for &(ref text, highlight) in row {
+      if highlight {
+        line.push_str(&format!("[{:>1$}]", text, width));
+      } else {
+        line.push_str(&format!(" {:>1$} ", text, width));
+      }
+    }
+    out.push_str(line.trim_end());
+    out.push('\n');
+  }
+  return out;
+}
+
+// each square a box wide enough for the widest value, a monospace digit being about 0.6em
+fn svg_grid(rows: &[Vec<(String, bool)>], width: usize, size: i64, path: &[Point]) -> String {
+  let font = 14;
+  let side = (width * font * 6 / 10 + font).max(2 * font);
+  let extent = side * rows.len();
+
+  let mut out = format!(
+    "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{0}\" viewBox=\"0 0 {0} {0}\" \
+     font-family=\"monospace\" font-size=\"{1}\">\n", extent, font);
+  for (r, row) in rows.iter().enumerate() {
+    for (c, &(ref text, highlight)) in row.iter().enumerate() {
+      let (x, y) = (c * side, r * side);
+      let fill = if highlight { "#fd6" } else { "#fff" };
+      out.push_str(&format!(
+        "  <rect x=\"{}\" y=\"{}\" width=\"{2}\" height=\"{2}\" fill=\"{3}\" stroke=\"#999\"/>\n",
+        x, y, side, fill));
+      out.push_str(&format!(
+        "  <text x=\"{}\" y=\"{}\" text-anchor=\"middle\" dominant-baseline=\"central\">{}</text>\n",
+        x + side / 2, y + side / 2, text));
+    }
+  }
+
+  // through the middle of the route's squares that are in the picture
+  let points: Vec<String> = path.iter()
+    .filter(|p| p.x.abs() <= size && p.y.abs() <= size)
+    .map(|p| {
+      let (c, r) = ((p.x + size) as usize, (size - p.y) as usize);
+      return format!("{},{}", c * side + side / 2, r * side + side / 2);
+    })
+    .collect();
+  if points.len() > 1 {
+    out.push_str(&format!(
+      "  <polyline points=\"{}\" fill=\"none\" stroke=\"#c00\" stroke-width=\"2\"/>\n",
+      points.join(" ")));
+  }
+  out.push_str("</svg>\n");
+  return out;
+}
+
+// The smallest `r` with `r * r >= n`. A float square root is only a first guess: past 2^52 it can
+// land either side of the answer.
+fn ceil_sqrt(n: i128) -> i128 {
+  let mut r = (n as f64).sqrt() as i128;
+  while r * r < n { r += 1; }
+  while r > 0 && (r - 1) * (r - 1) >= n { r -= 1; }
+  return r;
+}
+
+#[cfg(test)]
+mod sparse_matrix_tests {
+  use super::*;
+
+  #[test]
+  fn has_positive() {
+    let mut sm = SparseMatrix::new();
+    sm.get(Point::new(0,1));
+    assert!(sm.has(Point::new(0,0)));
+    assert!(sm.has(Point::new(1,1)));
+    assert!(sm.has(Point::new(1,0)));
+    assert!(sm.has(Point::new(0,1)));
+  }
+
+  #[test]
+  fn has_negative() {
+    let sm = SparseMatrix::new();
+    assert!(!sm.has(Point::new(1,1)));
+    assert!(!sm.has(Point::new(1,0)));
+  }
+
+  #[test]
+  fn new_happy()  {
+    let mut sm = SparseMatrix::new();
+    assert_eq!(sm.get(Point::new(0,0)), 1);
+  }
+
+  #[test]
+  fn get_automatically_calculates_and_populates() {
+    let mut sm = SparseMatrix::new();
+    assert_eq!(sm.get(Point::new(2,2)), 59);
+    assert_eq!(sm.get(Point::new(1,-1)), 25);
+    assert_eq!(sm.get(Point::new(-2,2)), 147);
+    assert_eq!(sm.get(Point::new(-2,1)), 304);
+    assert_eq!(sm.get(Point::new(-2,-2)), 362);
+  }
+
+  #[test]
+  fn spiral_to_point_1() {
+    let p = SparseMatrix::spiral_to_point(5);
+    assert_eq!(p.x, -1);
+    assert_eq!(p.y, 1);
+  }
+
+  #[test]
+  fn spiral_to_point_2() {
+    let p = SparseMatrix::spiral_to_point(16);
+    assert_eq!(p.x, -1);
+    assert_eq!(p.y, 2);
+  }
+
+  #[test]
+  fn spiral_to_point_3() {
+    let p = SparseMatrix::spiral_to_point(12);
+    assert_eq!(p.x, 2);
+    assert_eq!(p.y, 1);
+  }
+
+  #[test]
+  fn spiral_to_point_4() {
+    let p = SparseMatrix::spiral_to_point(22);
+    assert_eq!(p.x, -1);
+    assert_eq!(p.y, -2);
+  }
+
+  #[test]
+  fn spiral_to_point_5() {
+    let p = SparseMatrix::spiral_to_point(22);
+    assert_eq!(p.x, -1);
+    assert_eq!(p.y, -2);
+  }
+
+  #[test]
+  fn spiral_to_point_on_axis_1() {
+    let p = SparseMatrix::spiral_to_point(11);
+    assert_eq!(p.x, 2);
+    assert_eq!(p.y, 0);
+  }
+
+  #[test]
+  fn part1_test() {
+    // we don't need this for solving the puzzle, but it's a good test of the
+    // spiral/index conversion
+    let p = SparseMatrix::spiral_to_point(368078);
+    assert_eq!(p.x, -68);
+    assert_eq!(p.y, -303);
+  }
+
+  #[test]
+  fn spiral_to_point_indexes_at_1() {
+    let p = SparseMatrix::spiral_to_point(1);
+    assert_eq!(p.x, 0);
+    assert_eq!(p.y, 0);
+  }
+
+  #[test]
+  fn spiral_to_point_generates_spiral_correctly() {
+    let p = SparseMatrix::spiral_to_point(1);
+    assert_eq!(p.x, 0); assert_eq!(p.y, 0);
+
+    let p = SparseMatrix::spiral_to_point(2);
+    assert_eq!(p.x, 1); assert_eq!(p.y, 0);
+
+    let p = SparseMatrix::spiral_to_point(3);
+    assert_eq!(p.x, 1); assert_eq!(p.y, 1);
+
+    let p = SparseMatrix::spiral_to_point(4);
+    assert_eq!(p.x, 0); assert_eq!(p.y, 1);
+
+    let p = SparseMatrix::spiral_to_point(5);
+    assert_eq!(p.x, -1); assert_eq!(p.y, 1);
+
+    let p = SparseMatrix::spiral_to_point(6);
+    assert_eq!(p.x, -1); assert_eq!(p.y, 0);
+
+    let p = SparseMatrix::spiral_to_point(7);
+    assert_eq!(p.x, -1); assert_eq!(p.y, -1);
+
+    let p = SparseMatrix::spiral_to_point(8);
+    assert_eq!(p.x, 0); assert_eq!(p.y, -1);
+
+    let p = SparseMatrix::spiral_to_point(9);
+    assert_eq!(p.x, 1); assert_eq!(p.y, -1);
+
+    let p = SparseMatrix::spiral_to_point(10);
+    assert_eq!(p.x, 2); assert_eq!(p.y, -1);
+
+    let p = SparseMatrix::spiral_to_point(11);
+    assert_eq!(p.x, 2); assert_eq!(p.y, 0);
+
+    let p = SparseMatrix::spiral_to_point(18);
+    assert_eq!(p.x, -2); assert_eq!(p.y, 1);
+
+    let p = SparseMatrix::spiral_to_point(20);
+    assert_eq!(p.x, -2); assert_eq!(p.y, -1);
+  }
+
+  #[test]
+  fn point_to_spiral_examples() {
+    assert_eq!(SparseMatrix::point_to_spiral(Point::new(0, 0)), 1);
+    assert_eq!(SparseMatrix::point_to_spiral(Point::new(2, 1)), 12);
+    assert_eq!(SparseMatrix::point_to_spiral(Point::new(-1, -2)), 22);
+    assert_eq!(SparseMatrix::point_to_spiral(Point::new(1, -1)), 9);
+    assert_eq!(SparseMatrix::point_to_spiral(Point::new(-68, -303)), 368078);
+  }
+
+  #[test]
+  fn point_to_spiral_inverts_every_early_square() {
+    for idx in 1..200_000 {
+      assert_eq!(SparseMatrix::point_to_spiral(SparseMatrix::spiral_to_point(idx)), idx);
+    }
+  }
+
+  #[test]
+  fn point_to_spiral_inverts_large_squares() {
+    use generate::Rng;
+
+    let mut rng = Rng::new(3);
+    for _ in 0..100_000 {
+      let idx = rng.range(1, i64::MAX - 1);
+      assert_eq!(SparseMatrix::point_to_spiral(SparseMatrix::spiral_to_point(idx)), idx);
+
+      let ring = rng.range(-30_000_000, 30_000_000);
+      let along = rng.range(-ring.abs(), ring.abs());
+      for &p in [Point::new(ring, along), Point::new(along, ring)].iter() {
+        assert_eq!(SparseMatrix::spiral_to_point(SparseMatrix::point_to_spiral(p)), p);
+      }
+    }
+  }
+
+  #[test]
+  fn point_to_spiral_numbers_each_square_once() {
+    // every point out to ring 100 gets a different square, and between them they're 1 to 201^2
+    let mut seen = vec![false; 201 * 201 + 1];
+    for x in -100..101 {
+      for y in -100..101 {
+        let idx = SparseMatrix::point_to_spiral(Point::new(x, y)) as usize;
+        assert!(!seen[idx], "{} twice", idx);
+        seen[idx] = true;
+      }
+    }
+    assert!(seen[1..].iter().all(|&s| s));
+  }
+
+  #[test]
+  fn part1_examples() {
+    assert_eq!(part1(1), 0);
+    assert_eq!(part1(12), 3);
+    assert_eq!(part1(23), 2);
+    assert_eq!(part1(1024), 31);
+  }
+
+  #[test]
+  fn part2_examples() {
+    assert_eq!(part2(1), Ok(2));
+    assert_eq!(part2(59), Ok(122));
+    assert_eq!(part2(747), Ok(806));
+  }
+
+  #[test]
+  fn part2_past_the_largest_value() {
+    let mut sm = SparseMatrix::new();
+    let mut largest = 0;
+    let overflow = loop {
+      match sm.try_tip() {
+        Ok(v) => largest = v,
+        Err(e) => break e
+      }
+    };
+
+    assert_eq!(part2(largest - 1), Ok(largest));
+    assert_eq!(part2(largest), Err(overflow));
+    assert_eq!(part2(i64::MAX), Err(overflow));
+    // nothing was written for the square that overflowed
+    assert!(!sm.has(sm.pointer()));
+    assert_eq!(SparseMatrix::point_to_spiral(sm.pointer()), overflow.square);
+  }
+
+  #[test]
+  fn spiral_to_point_near_perfect_squares() {
+    // where a float square root rounds the wrong way
+    for &k in [94906265i64, 3037000499, 2147483647].iter() {
+      for idx in [k*k - 1, k*k, k*k + 1].iter() {
+        assert_eq!(SparseMatrix::point_to_spiral(SparseMatrix::spiral_to_point(*idx)), *idx);
+      }
+    }
+  }
+
+  #[test]
+  fn largest_squares() {
+    let p = SparseMatrix::spiral_to_point(i64::MAX);
+    assert_eq!(SparseMatrix::point_to_spiral(p), i64::MAX);
+    assert_eq!(part1(i64::MAX), p.x.abs() + p.y.abs());
+    assert_eq!(Day3.parse("9223372036854775807"), Ok(i64::MAX));
+
+    // the next ring out starts past the largest
+    let n = p.x.abs().max(p.y.abs());
+    assert_eq!(SparseMatrix::checked_point_to_spiral(Point::new(n + 1, 0)), None);
+    assert_eq!(SparseMatrix::checked_point_to_spiral(Point::new(i64::MIN, i64::MAX)), None);
+  }
+
+  #[test]
+  fn solver_reports_targets_past_the_largest_value() {
+    use solution::Solver;
+
+    let target = i64::MAX.to_string();
+    assert_eq!(Day3.solve(1, &target), Ok(Answer::Number(part1(i64::MAX))));
+    assert_eq!(Day3.solve(2, &target), Err(SolveError::Unsolvable(format!(
+      "the value for square 464 overflows an i64, so nothing written is larger than {}", target))));
+  }
+
+  #[test]
+  fn parse_rejects_squares_before_1() {
+    assert_eq!(Day3.parse("368078\n"), Ok(368078));
+    assert!(Day3.parse("0").is_err());
+  }
+  #[test]
+  fn observed_fills() {
+    use observe::Recorder;
+
+    let mut recorder = Recorder::new();
+    let mut sm = SparseMatrix::new();
+    assert_eq!(sm.get_observed(Point::new(-1, 1), &mut recorder), 5);
+    let values: Vec<i64> = recorder.events.iter().map(|f| f.value).collect();
+    assert_eq!(values, vec![1, 2, 4, 5]);
+    assert_eq!(recorder.events[0], Fill { square: 2, point: Point::new(1, 0), value: 1 });
+  }
+
+  // the values in squares 1 to `n`
+  fn first<R: FillRule>(sm: &mut SparseMatrix<R>, n: i64) -> Vec<i64> {
+    return (1..(n + 1)).map(|idx| sm.get(SparseMatrix::spiral_to_point(idx))).collect();
+  }
+
+  #[test]
+  fn adjacent_sum() {
+    let mut sm = SparseMatrix::with_rule(Sum(Neighbourhood::Adjacent), 1);
+    assert_eq!(first(&mut sm, 10), vec![1, 1, 1, 2, 2, 3, 3, 4, 5, 5]);
+  }
+
+  #[test]
+  fn product_until_it_overflows() {
+    let mut sm = SparseMatrix::with_rule(Product(Neighbourhood::Surrounding), 2);
+    assert_eq!(first(&mut sm, 6), vec![2, 2, 4, 16, 32, 1024]);
+
+    let overflow = loop {
+      if let Err(e) = sm.try_tip() { break e; }
+    };
+    assert!(!sm.has(SparseMatrix::spiral_to_point(overflow.square)));
+  }
+
+  #[test]
+  fn max_spreads_the_origin() {
+    let mut sm = SparseMatrix::with_rule(Max(Neighbourhood::Adjacent), 5);
+    assert_eq!(first(&mut sm, 25), vec![5; 25]);
+  }
+
+  #[test]
+  fn closures_see_the_index() {
+    let mut sm = SparseMatrix::with_rule(|square: i64, values: &[i64]| {
+      return values.iter().sum::<i64>().checked_add(square);
+    }, 1);
+    assert_eq!(first(&mut sm, 4), vec![1, 3, 7, 15]);
+  }
+
+  #[test]
+  fn a_summing_closure_is_the_stress_test() {
+    let mut sm = SparseMatrix::with_rule(|_: i64, values: &[i64]| {
+      return values.iter().try_fold(0i64, |acc, v| acc.checked_add(*v));
+    }, 1);
+    assert_eq!(first(&mut sm, 200), first(&mut SparseMatrix::new(), 200));
+  }
+
+  #[test]
+  fn get_fills_lazily() {
+    let mut sm = SparseMatrix::with_rule(Sum(Neighbourhood::Adjacent), 1);
+    assert!(!sm.has(Point::new(1, -1)));
+    assert_eq!(sm.get(Point::new(1, -1)), 5);
+    // filled up to square 9 and no further
+    assert_eq!(SparseMatrix::point_to_spiral(sm.pointer()), 10);
+    assert!(!sm.has(sm.pointer()));
+  }
+
+  fn every_spiral() -> Vec<Spiral> {
+    let mut spirals = vec![];
+    for &rotation in [Rotation::CounterClockwise, Rotation::Clockwise].iter() {
+      for &start in [Direction::Right, Direction::Up, Direction::Left, Direction::Down].iter() {
+        spirals.push(Spiral::new().with_rotation(rotation).with_start(start));
+      }
+    }
+    return spirals;
+  }
+
+  #[test]
+  fn spiral_iter_is_the_puzzle_spiral() {
+    for (idx, p) in Spiral::new().iter().take(10000) {
+      assert_eq!(p, SparseMatrix::spiral_to_point(idx));
+    }
+  }
+
+  #[test]
+  fn spiral_iter_orientations() {
+    let points = |spiral: Spiral| spiral.iter().take(5).map(|(_, p)| p).collect::<Vec<Point>>();
+    let clockwise = Spiral::new().with_rotation(Rotation::Clockwise);
+    assert_eq!(points(clockwise), vec![
+      Point::new(0, 0), Point::new(1, 0), Point::new(1, -1), Point::new(0, -1), Point::new(-1, -1)
+    ]);
+    assert_eq!(points(Spiral::new().with_start(Direction::Up)), vec![
+      Point::new(0, 0), Point::new(0, 1), Point::new(-1, 1), Point::new(-1, 0), Point::new(-1, -1)
+    ]);
+    assert_eq!(points(clockwise.with_start(Direction::Left)), vec![
+      Point::new(0, 0), Point::new(-1, 0), Point::new(-1, 1), Point::new(0, 1), Point::new(1, 1)
+    ]);
+  }
+
+  #[test]
+  fn spiral_iter_from_another_index() {
+    let spiral = Spiral::new().with_first(0);
+    let first: Vec<(i64, Point)> = spiral.iter().take(3).collect();
+    assert_eq!(first, vec![(0, Point::new(0, 0)), (1, Point::new(1, 0)), (2, Point::new(1, 1))]);
+    assert_eq!(spiral.point(368077), SparseMatrix::spiral_to_point(368078));
+    assert_eq!(spiral.index(Point::new(-68, -303)), 368077);
+
+    // the last square is still `i64::MAX`, however far round that is
+    let late = Spiral::new().with_first(i64::MAX - 1);
+    let last: Vec<(i64, Point)> = late.iter().collect();
+    assert_eq!(last, vec![(i64::MAX - 1, Point::new(0, 0)), (i64::MAX, Point::new(1, 0))]);
+  }
+
+  #[test]
+  fn spirals_agree_with_their_iterators() {
+    use std::collections::HashSet;
+
+    for spiral in every_spiral() {
+      let mut seen = HashSet::new();
+      let mut last = None;
+      for (idx, p) in spiral.iter().take(2000) {
+        assert_eq!(spiral.point(idx), p);
+        assert_eq!(spiral.index(p), idx);
+        assert!(seen.insert(p));
+        // each square is a step on from the one before
+        if let Some(q) = last {
+          assert!(p.adjacent().contains(&q));
+        }
+        last = Some(p);
+      }
+    }
+  }
+
+  #[test]
+  fn spirals_round_trip_far_out() {
+    use generate::Rng;
+
+    let mut rng = Rng::new(24);
+    for spiral in every_spiral() {
+      let spiral = spiral.with_first(rng.range(-1000, 1000));
+      for _ in 0..100 {
+        // far enough in that the last square fits either way the first one moves it
+        let idx = rng.range(1000, i64::MAX - 1000);
+        assert_eq!(spiral.index(spiral.point(idx)), idx);
+      }
+      assert_eq!(spiral.checked_index(Point::new(i64::MIN, 0)), None);
+    }
+  }
+
+  #[test]
+  fn matrices_fill_along_any_spiral() {
+    let mut puzzle = SparseMatrix::new();
+    let mirrored = Spiral::new().with_rotation(Rotation::Clockwise).with_first(0);
+    let mut sm = SparseMatrix::new().along(mirrored);
+
+    assert_eq!(sm.tip(), 1);
+    assert_eq!(sm.pointer(), Point::new(1, -1));
+    for (idx, p) in mirrored.iter().take(100) {
+      assert_eq!(sm.get(p), puzzle.get(Point::new(p.x, -p.y)));
+      assert_eq!(idx + 1, SparseMatrix::point_to_spiral(Point::new(p.x, -p.y)));
+    }
+  }
+
+  #[test]
+  fn routes_are_as_long_as_part1() {
+    for idx in 1..1000 {
+      let path = route(SparseMatrix::spiral_to_point(idx));
+      assert_eq!(path.len() as i64, part1(idx) + 1);
+      assert_eq!(path[0], SparseMatrix::spiral_to_point(idx));
+      assert_eq!(path[path.len() - 1], Point::new(0, 0));
+      for step in path.windows(2) {
+        assert!(step[0].adjacent().contains(&step[1]));
+      }
+    }
+    assert_eq!(route(Point::new(-1, 2)), vec![
+      Point::new(-1, 2), Point::new(0, 2), Point::new(0, 1), Point::new(0, 0)
+    ]);
+  }
+
+  #[test]
+  fn ascii_with_a_route() {
+    let mut sm = SparseMatrix::new();
+    sm.get(SparseMatrix::spiral_to_point(12));
+
+    let picture = Picture::ascii().with_route(12).render(&sm);
+    assert_eq!(picture, concat!(
+      "  .   .   .   .   .\n",
+      "  .   5 [ 4][ 2][57]\n",
+      "  .  10 [ 1]  1  54\n",
+      "  .  11  23  25  26\n",
+      "  .   .   .   .   .\n"
+    ));
+
+    // only as far as asked, and no further than what's filled
+    assert_eq!(Picture::ascii().with_size(1).render(&SparseMatrix::new()), concat!(
+      " .  .  .\n",
+      " .  1  .\n",
+      " .  .  .\n"
+    ));
+  }
+
+  #[test]
+  fn pictures_leave_the_matrix_alone() {
+    let mut sm = SparseMatrix::new();
+    sm.get(SparseMatrix::spiral_to_point(30));
+    let pointer = sm.pointer();
+
+    Picture::ascii().with_size(10).with_route(500).render(&sm);
+    Picture::svg().with_size(10).with_route(500).render(&sm);
+    assert_eq!(sm.pointer(), pointer);
+    assert!(!sm.has(pointer));
+  }
+
+  #[test]
+  fn svg_with_a_route() {
+    let mut sm = SparseMatrix::with_rule(Sum(Neighbourhood::Adjacent), 1);
+    sm.get(SparseMatrix::spiral_to_point(25));
+
+    let picture = Picture::svg().with_route(22).render(&sm);
+    assert!(picture.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"150\""));
+    assert_eq!(picture.matches("<rect").count(), 25);
+    assert_eq!(picture.matches("fill=\"#fd6\"").count(), 4);
+    assert!(picture.contains("<polyline points=\"45,135 75,135 75,105 75,75\""));
+    assert!(picture.ends_with("</svg>\n"));
+
+    // clipped to the picture
+    let small = Picture::svg().with_size(1).with_route(22).render(&sm);
+    assert_eq!(small.matches("<rect").count(), 9);
+    assert!(small.contains("<polyline points=\"42,70 42,42\""));
+  }
+}
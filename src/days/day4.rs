@@ -1,24 +1,33 @@
-use std::fs::File;
-use std::io::prelude::*;
 use std::collections::HashMap;
 
-fn main() {
-  println!("Advent of Code Day 4");
-  println!("");
-  println!("Spreadsheet: http://adventofcode.com/4017/day/2");
-  println!("");
+use parsing::ParseError;
+use solution::{Answer, Solution};
 
-  let mut file = File::open("data/day4/input").expect("file not found");
-  let mut content = String::new();
-  file.read_to_string(&mut content).expect("Something went wrong reading input file");
+#[cfg(test)]
+use input::read_day_file;
 
-  println!("Part1: {}", part1(&content));
-  println!("Part2: {}", part2(&content));
+pub struct Day4;
 
-}
+impl Solution for Day4 {
+  type Input = String;
+
+  fn day(&self) -> u8 { return 4; }
+  fn title(&self) -> &'static str { return "High-Entropy Passphrases"; }
+
+  fn parse(&self, input: &str) -> Result<String, ParseError> {
+    return Ok(input.to_owned());
+  }
 
+  fn part1(&self, input: &String) -> Answer {
+    return Answer::from(part1(input));
+  }
+
+  fn part2(&self, input: &String) -> Answer {
+    return Answer::from(part2(input));
+  }
+}
 
-fn part1(input: &String) -> i32 {
+pub fn part1(input: &str) -> i32 {
   let mut invalid = 0;
   let mut total = 0;
   let mut hash: HashMap<String, i32>;
@@ -48,7 +57,7 @@ fn part1(input: &String) -> i32 {
   return total - invalid;
 }
 
-fn part2(input: &String) -> i32 {
+pub fn part2(input: &str) -> i32 {
   let mut invalid = 0;
   let mut total = 0;
   let mut hash: HashMap<String, i32>;
@@ -82,25 +91,21 @@ fn part2(input: &String) -> i32 {
 fn sort_string(input: String) -> String {
   let mut chars: Vec<char> = input.chars().collect();
 
-  chars.sort_by(|a,b| a.cmp(b));
+  chars.sort();
 
   return chars.into_iter().collect();
 }
 
 #[test]
 fn test_part1() {
-  let mut file = File::open("data/day4/test").expect("file not found");
-  let mut content = String::new();
-  file.read_to_string(&mut content).expect("Something went wrong reading test file");
+  let content = read_day_file(4, "test");
 
   assert_eq!(part1(&content), 2);
 }
 
 #[test]
 fn test_part2() {
-  let mut file = File::open("data/day4/test2").expect("file not found");
-  let mut content = String::new();
-  file.read_to_string(&mut content).expect("Something went wrong reading test file");
+  let content = read_day_file(4, "test2");
 
   assert_eq!(part2(&content), 3);
 }
@@ -0,0 +1,207 @@
+use observe::Observer;
+use parsing::{parse_all, ParseError};
+use solution::{Answer, Solution};
+
+#[cfg(test)]
+use input::read_day_file;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+  type Input = Vec<i32>;
+
+  fn day(&self) -> u8 { return 5; }
+  fn title(&self) -> &'static str { return "A Maze of Twisty Trampolines, All Alike"; }
+
+  fn parse(&self, input: &str) -> Result<Vec<i32>, ParseError> {
+    return Machine::parse_source(input);
+  }
+
+  fn part1(&self, input: &Vec<i32>) -> Answer {
+    return Answer::from(Machine::from_program(input.clone(), Mode::Part1, false).run());
+  }
+
+  fn part2(&self, input: &Vec<i32>) -> Answer {
+    return Answer::from(Machine::from_program(input.clone(), Mode::Part2, false).run());
+  }
+}
+
+pub fn part1(input: &str) -> Result<i32, ParseError> {
+  return Ok(Machine::new(input.to_owned(), Mode::Part1, false)?.run());
+}
+
+pub fn part2(input: &str) -> Result<i32, ParseError> {
+  return Ok(Machine::new(input.to_owned(), Mode::Part2, false)?.run());
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Mode {
+  Part1,
+  Part2
+}
+
+// one step: the offset at `from` is followed, then changed
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Jump {
+  pub from: i32,
+  pub offset: i32
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Machine {
+  program: Vec<i32>,
+  code: Vec<i32>,
+  pointer: i32,
+  steps: i32,
+  mode: Mode,
+  debug: bool
+}
+
+impl Machine {
+  pub fn new(input: String, mode: Mode, debug: bool) -> Result<Machine, ParseError> {
+    let program = Machine::parse_source(&input)?;
+    return Ok(Machine::from_program(program, mode, debug));
+  }
+
+  pub fn from_program(program: Vec<i32>, mode: Mode, debug: bool) -> Machine {
+    return Machine {
+      program: program,
+      code: vec![],
+      steps: 0,
+      pointer: 0,
+      mode: mode,
+      debug: debug
+    };
+  }
+
+  pub fn reboot(&mut self) {
+    self.code = self.program.clone();
+    self.pointer = 0;
+    self.steps = 0;
+  }
+
+  pub fn display(&self) {
+    println!("----");
+    for inst in &self.code {
+      println!("{}", inst);
+    }
+    println!("----");
+  }
+
+  pub fn run(&mut self) -> i32 {
+    if self.debug {
+      return self.run_observed(&mut DisplayTape);
+    }
+    return self.run_observed(&mut ());
+  }
+
+  pub fn run_observed<O: Observer<Machine, Jump>>(&mut self, observer: &mut O) -> i32 {
+    self.reboot();
+    while self.in_bounds() {
+      let jump = Jump { from: self.pointer, offset: self.current_instruction() };
+      observer.before(self, &jump);
+      self.step();
+      observer.after(self, &jump);
+    }
+    return self.steps;
+  }
+
+  pub fn pointer(&self) -> i32 {
+    return self.pointer;
+  }
+
+  pub fn steps(&self) -> i32 {
+    return self.steps;
+  }
+
+  // the offsets as they stand, changed by every jump
+  pub fn tape(&self) -> &[i32] {
+    return &self.code;
+  }
+
+  // one jump offset per line
+  pub fn parse_source(source: &str) -> Result<Vec<i32>, ParseError> {
+    return parse_all(source);
+  }
+
+  fn step(&mut self) {
+    let previous = self.pointer;
+
+    self.pointer += self.current_instruction();
+    self.update_instruction(previous);
+
+    self.steps += 1
+  }
+
+  fn update_instruction(&mut self, ptr: i32) {
+    match self.mode {
+      Mode::Part1 => self.code[ptr as usize] += 1,
+      Mode::Part2 => {
+        let instruction = self.code[ptr as usize];
+        if instruction >= 3 {
+          self.code[ptr as usize] -= 1;
+        } else {
+          self.code[ptr as usize] += 1;
+        }
+      }
+    }
+  }
+
+
+  fn current_instruction(&self) -> i32 {
+    return self.code[self.pointer as usize];
+  }
+
+  fn upper_bound(&self) -> i32 {
+    return self.code.len() as i32;
+  }
+
+  fn in_bounds(&self) -> bool {
+    return self.pointer >= 0 && self.pointer < self.upper_bound();
+  }
+}
+
+// what `debug` does: the whole tape before every jump
+struct DisplayTape;
+
+impl Observer<Machine, Jump> for DisplayTape {
+  fn before(&mut self, machine: &Machine, _jump: &Jump) {
+    machine.display();
+  }
+}
+
+#[test]
+fn part1_test() {
+  let content = read_day_file(5, "test");
+
+  assert_eq!(Machine::new(content, Mode::Part1, true).unwrap().run(), 5);
+}
+
+#[test]
+fn part2_test() {
+  let content = read_day_file(5, "test");
+
+  assert_eq!(Machine::new(content, Mode::Part2, false).unwrap().run(), 10);
+}
+
+#[test]
+fn malformed_offset() {
+  let e = Machine::new(String::from("0\n3\n+-1"), Mode::Part1, false).unwrap_err();
+  assert_eq!((e.line, e.column), (3, 1));
+  assert_eq!(e.text, "+-1");
+}
+
+#[test]
+fn observed_jumps() {
+  use observe::{Counter, Recorder};
+
+  let mut recorder = Recorder::new();
+  let mut m = Machine::from_program(vec![0, 3, 0, 1, -3], Mode::Part1, false);
+  assert_eq!(m.run_observed(&mut recorder), 5);
+  assert_eq!(recorder.events[..2], [Jump { from: 0, offset: 0 }, Jump { from: 0, offset: 1 }]);
+  assert_eq!(m.tape(), &[2, 5, 0, 1, -2]);
+
+  let mut counter = Counter::new();
+  Machine::from_program(vec![0, 3, 0, 1, -3], Mode::Part2, false).run_observed(&mut counter);
+  assert_eq!(counter.steps, 10);
+}
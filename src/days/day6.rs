@@ -0,0 +1,211 @@
+use std::collections::HashSet;
+
+use observe::Observer;
+use parsing::{parse_all, ParseError};
+use solution::{Answer, Solution};
+
+#[cfg(test)]
+use input::read_day_file;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+  type Input = Vec<i32>;
+
+  fn day(&self) -> u8 { return 6; }
+  fn title(&self) -> &'static str { return "Memory Reallocation"; }
+
+  fn parse(&self, input: &str) -> Result<Vec<i32>, ParseError> {
+    return MemoryBank::parse_source(input);
+  }
+
+  fn part1(&self, input: &Vec<i32>) -> Answer {
+    return Answer::from(MemoryBank::from_blocks(input.clone(), Mode::Part1).run());
+  }
+
+  fn part2(&self, input: &Vec<i32>) -> Answer {
+    return Answer::from(MemoryBank::from_blocks(input.clone(), Mode::Part2).run());
+  }
+}
+
+pub fn part1(input: &str) -> Result<i32, ParseError> {
+  return Ok(MemoryBank::new(input, Mode::Part1)?.run());
+}
+
+pub fn part2(input: &str) -> Result<i32, ParseError> {
+  return Ok(MemoryBank::new(input, Mode::Part2)?.run());
+}
+
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Mode {
+  Part1,
+  Part2
+}
+
+// one step: the bank with the most blocks hands them out, one to each bank after it
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Reallocation {
+  pub bank: usize,
+  pub blocks: i32
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct MemoryBank {
+  initial: Vec<i32>,
+  history: HashSet<Vec<i32>>,
+  blocks: Vec<i32>,
+  mode: Mode,
+  steps: i32,
+  loop_found: bool
+}
+
+impl MemoryBank {
+  pub fn new(source: &str, mode: Mode) -> Result<MemoryBank, ParseError> {
+    let blocks = MemoryBank::parse_source(source)?;
+    return Ok(MemoryBank::from_blocks(blocks, mode));
+  }
+
+  pub fn from_blocks(blocks: Vec<i32>, mode: Mode) -> MemoryBank {
+    return MemoryBank {
+      initial: blocks,
+      history: HashSet::new(),
+      blocks: vec![],
+      mode: mode,
+      steps: 0,
+      loop_found: false
+    };
+  }
+
+  pub fn run(&mut self) -> i32 {
+    return self.run_observed(&mut ());
+  }
+
+  pub fn run_observed<O: Observer<MemoryBank, Reallocation>>(&mut self, observer: &mut O) -> i32 {
+    self.reboot();
+
+    self.execution_loop(observer);
+
+    if self.mode == Mode::Part2 {
+      self.steps = 0;        // reset to count the total steps in the loop
+      self.clear_history();   // wipe history
+      self.record_history();  // but populate it with state so we fail quick
+      self.execution_loop(observer);  // rerun
+    }
+
+    return self.steps
+  }
+
+  // the blocks in each bank as they stand
+  pub fn blocks(&self) -> &[i32] {
+    return &self.blocks;
+  }
+
+  pub fn steps(&self) -> i32 {
+    return self.steps;
+  }
+
+  fn execution_loop<O: Observer<MemoryBank, Reallocation>>(&mut self, observer: &mut O) {
+    while !self.loop_found {
+      let bank = self.largest_block_id();
+      let realloc = Reallocation { bank: bank, blocks: self.blocks[bank] };
+      observer.before(self, &realloc);
+      self.step(bank);
+      self.steps += 1;
+      observer.after(self, &realloc);
+      self.record_history();
+    }
+  }
+
+  fn step(&mut self, start_idx: usize) {
+    let memory = self.blocks[start_idx];
+    let len = self.blocks.len();
+
+    self.blocks[start_idx] = 0;
+    for offset in 0..memory {
+      self.blocks[(start_idx + (offset as usize) + 1) % len] += 1;
+    }
+  }
+
+  fn seen(&self, entry: &Vec<i32>) -> bool {
+    return self.history.contains(entry);
+  }
+
+  fn record_history(&mut self) {
+    let entry = self.blocks.to_owned();
+    self.loop_found = self.seen(&entry);
+    self.history.insert(entry.to_owned());
+  }
+
+  fn largest_block_id(&self) -> usize {
+    let max = self.largest_block_allocation();
+
+    for idx in 0..self.blocks.len() {
+      if self.blocks[idx] == max {
+        return idx;
+      }
+    }
+    panic!("Cannot occur!");
+  }
+
+  fn largest_block_allocation(&self) -> i32 {
+    match self.blocks.iter().max() {
+      Some(v) => return v.to_owned(),
+      None => panic!("No max value found!")
+    }
+  }
+
+  fn reboot(&mut self) {
+    self.blocks = self.initial.clone();
+    self.clear_history();
+    self.steps = 0;
+    self.loop_found = false;
+  }
+
+  fn clear_history(&mut self) {
+    self.history.clear();
+  }
+
+  // the block count of each bank, separated by whitespace
+  pub fn parse_source(source: &str) -> Result<Vec<i32>, ParseError> {
+    let blocks = parse_all(source)?;
+    if blocks.is_empty() {
+      return Err(ParseError::new(source.trim(), "at least one memory bank"));
+    }
+    return Ok(blocks);
+  }
+
+}
+
+#[test]
+fn part1_test() {
+  let content = read_day_file(6, "test");
+
+  assert_eq!(MemoryBank::new(&content, Mode::Part1).unwrap().run(), 5);
+}
+
+#[test]
+fn part2_test() {
+  let content = read_day_file(6, "test");
+
+  assert_eq!(MemoryBank::new(&content, Mode::Part2).unwrap().run(), 4);
+}
+
+#[test]
+fn malformed_banks() {
+  let e = MemoryBank::new("0\t2\tseven\t0", Mode::Part1).unwrap_err();
+  assert_eq!((e.line, e.column), (1, 5));
+  assert!(MemoryBank::new("\n", Mode::Part1).is_err());
+}
+
+#[test]
+fn observed_reallocations() {
+  use observe::Recorder;
+
+  let mut recorder = Recorder::new();
+  let mut bank = MemoryBank::from_blocks(vec![0, 2, 7, 0], Mode::Part1);
+  assert_eq!(bank.run_observed(&mut recorder), 5);
+  assert_eq!(recorder.events[0], Reallocation { bank: 2, blocks: 7 });
+  assert_eq!(recorder.events.len(), 5);
+  assert_eq!(bank.blocks(), &[2, 4, 1, 2]);
+}
@@ -0,0 +1,373 @@
+use parsing::{fields, parse_as, tokens, ParseError};
+use solution::{Answer, Solution, SolveError};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+  type Input = ProgramTree;
+
+  fn day(&self) -> u8 { return 7; }
+  fn title(&self) -> &'static str { return "Recursive Circus"; }
+
+  fn parse(&self, input: &str) -> Result<ProgramTree, ParseError> {
+    let mut pt = ProgramTree::empty();
+    pt.parse(input)?;
+    return Ok(pt);
+  }
+
+  // every program sits on another when they stand in a loop
+  fn try_part1(&self, input: &ProgramTree) -> Result<Answer, SolveError> {
+    match input.root() {
+      Some(p) => return Ok(Answer::from(p.name)),
+      None => return Err(SolveError::Unsolvable(String::from("no program is at the bottom of the tower")))
+    }
+  }
+
+  fn try_part2(&self, input: &ProgramTree) -> Result<Answer, SolveError> {
+    match input.balancing_weight() {
+      Some(w) => return Ok(Answer::from(w)),
+      None if input.root().is_none() => return Err(SolveError::Unsolvable(String::from("no program is at the bottom of the tower"))),
+      None => return Err(SolveError::Unsolvable(String::from("the tower is already balanced")))
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Program {
+  name: String,
+  initial_weight: Option<i32>,
+  total_weight: Option<i32>
+}
+
+impl Program {
+  // looks like `pbga (66)', or just `pbga' when only the name is known
+  pub fn new(input: &str) -> Result<Program, ParseError> {
+    let expected = "a program like `pbga (66)'";
+    let parts = tokens(input);
+
+    match parts.as_slice() {
+      [(_, name)] if !name.contains('(') => {
+        return Ok(Program {
+          name: String::from(*name),
+          initial_weight: None,
+          total_weight: None
+        });
+      },
+      [(_, name), (column, weight)] if weight.starts_with('(') && weight.ends_with(')') && weight.len() > 1 => {
+        let initial_weight = parse_as::<i32>(&weight[1..weight.len() - 1]).map_err(|e| e.from_column(column + 1))?;
+
+        return Ok(Program {
+          name: String::from(*name),
+          initial_weight: Some(initial_weight),
+          total_weight: None
+        });
+      },
+      [] => return Err(ParseError::new("", expected)),
+      [(column, _), ..] => return Err(ParseError::new(input.trim(), expected).from_column(*column))
+    }
+  }
+}
+
+#[cfg(test)]
+mod program_tests {
+  use super::*;
+  #[test]
+  fn test_new_with_weight() {
+    let p = Program::new(&String::from("pbga (66)")).unwrap();
+    assert_eq!(p.name, String::from("pbga"));
+    assert_eq!(p.initial_weight, Some(66));
+  }
+
+  #[test]
+  fn test_new_without_weight() {
+    let p = Program::new(&String::from("pbga ")).unwrap();
+    assert_eq!(p.name, String::from("pbga"));
+    assert_eq!(p.initial_weight, None);
+  }
+
+  #[test]
+  fn test_new_malformed() {
+    let e = Program::new(&String::from("pbga -> ")).unwrap_err();
+    assert_eq!(e.text, "pbga ->");
+    assert_eq!(e.expected, "a program like `pbga (66)'");
+  }
+
+  #[test]
+  fn test_new_malformed_weight() {
+    let e = Program::new(&String::from("pbga (6x6)")).unwrap_err();
+    assert_eq!(e.column, 7);
+    assert_eq!(e.text, "6x6");
+  }
+
+  #[test]
+  fn trims_names_for_whitespace_weight() {
+    let p = Program::new(&String::from("  pbga     (66)")).unwrap();
+    assert_eq!(p.name, "pbga");
+    assert_eq!(p.initial_weight, Some(66));
+  }
+
+  #[test]
+  fn trims_names_for_whitespace_no_weight() {
+    let p = Program::new(&String::from("  pbga     ")).unwrap();
+    assert_eq!(p.name, "pbga");
+    assert_eq!(p.initial_weight, None);
+  }
+}
+
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ProgramTree {
+  vertices: Vec<Program>,
+  edges: Vec<(String,String)>
+}
+
+impl ProgramTree {
+  pub fn empty() -> ProgramTree {
+    return ProgramTree {
+      vertices: vec![],
+      edges: vec![]
+    };
+  }
+
+  pub fn parse(&mut self, input : &str) -> Result<(), ParseError> {
+    for (idx, line) in input.lines().enumerate() {
+      if !line.trim().is_empty() {
+        self.insert_line(line).map_err(|e| e.on_line(idx + 1))?;
+      }
+    }
+    return Ok(());
+  }
+
+  pub fn root(&self) -> Option<Program> {
+    for v in &self.vertices {
+      if self.is_source(v) && !self.is_target(v) {
+        return Some(v.to_owned());
+      }
+    }
+    return None;
+  }
+
+  // Walks down from the root towards the one program with the wrong weight, returns the weight it
+  // would need to balance the tower. `None` if the tower is balanced already.
+  pub fn balancing_weight(&self) -> Option<i32> {
+    let mut cursor = self.root()?;
+    let mut expected_total = None;
+
+    loop {
+      let targets = self.vertex_targets(&cursor.name);
+      let mut weights = vec![];
+      for target in &targets {
+        weights.push(self.vertex_total_weight(&target.name));
+      }
+
+      match ProgramTree::odd_one_out(&weights) {
+        Some(idx) => {
+          // the odd child is the only one that can be wrong, everything above it is only off
+          // because of it
+          expected_total = weights.iter().find(|&&w| w != weights[idx]).cloned();
+          cursor = targets[idx].to_owned();
+        },
+        None => {
+          // all the children agree, so the cursor itself is carrying the wrong weight
+          let actual_total = self.vertex_total_weight(&cursor.name);
+          let initial = cursor.initial_weight?;
+          return expected_total.map(|e| initial + e - actual_total);
+        }
+      }
+    }
+  }
+
+  // the index of the single weight that differs from all the others, if there is one
+  fn odd_one_out(weights: &[i32]) -> Option<usize> {
+    if weights.len() < 3 { return None; }
+
+    for (idx, w) in weights.iter().enumerate() {
+      if weights.iter().filter(|&v| v == w).count() == 1 {
+        return Some(idx);
+      }
+    }
+
+    return None;
+  }
+
+  fn is_source(&self, v: &Program) -> bool {
+    for e in &self.edges {
+      match *e {
+        (ref s, _) if *s == *v.name => return true,
+        _ => ()
+      }
+    }
+
+    return false;
+  }
+
+  fn is_target(&self, v: &Program) -> bool {
+    for e in &self.edges {
+      match *e {
+        (_, ref t) if *t == *v.name => return true,
+        _ => ()
+      }
+    }
+
+    return false;
+  }
+
+  fn insert_line(&mut self, input: &str) -> Result<(), ParseError> {
+    match input.find("->") {
+      Some(arrow) => {
+        let p = Program::new(&input[..arrow])?;
+
+        self.add_vertex(&p);
+
+        let targets_column = input[..arrow].chars().count() + 3;
+        for (column, edge_target) in fields(&input[arrow + 2..], ',') {
+          if edge_target.is_empty() {
+            return Err(ParseError::new(edge_target, "a program name").from_column(targets_column + column - 1));
+          }
+          self.add_edge(&p.name, edge_target);
+        }
+      },
+      None => {
+        let p = Program::new(input)?;
+        self.add_vertex(&p);
+      }
+    }
+    return Ok(());
+  }
+
+  fn add_vertex(&mut self, p : &Program) {
+    let np = p.to_owned();
+    self.vertices.push(np);
+  }
+
+  fn add_edge(&mut self, source: &str, target: &str) {
+    let a = source.to_owned();
+    let b = target.to_owned();
+    self.edges.push((a,b));
+  }
+
+  pub fn vertex_total_weight(&self, v_name: &str) -> i32 {
+    let mut source = self.find_vertex_by_name(v_name).expect("Could not find vertex");
+
+    match source.total_weight {
+      Some(v) => return v,
+      None => {
+        let targets = self.vertex_targets(v_name);
+
+        let mut sum = source.initial_weight.expect("Unset initial weight, cowardly exiting");
+
+        for target in targets {
+          sum += self.vertex_total_weight(&target.name);
+        }
+
+        source.total_weight = Some(sum);
+        return sum;
+      }
+    }
+  }
+
+  pub fn vertex_targets(&self, v_name: &str) -> Vec<Program> {
+    let mut ret = vec![];
+    for e in &self.edges {
+      match *e {
+        (ref s, ref t) if s == v_name => {
+          let v = self.find_vertex_by_name(t).expect("");
+          ret.push(v);
+        },
+        _ => ()
+      }
+    }
+    return ret;
+  }
+
+  fn find_vertex_by_name(&self, name: &str) -> Option<Program> {
+    for v in &self.vertices {
+      if v.name == *name {
+        let ret = Some(v.to_owned());
+        return ret;
+      }
+    }
+
+    return None;
+  }
+}
+
+#[cfg(test)]
+mod program_tree_tests {
+  use super::*;
+  use input::read_day_file;
+
+  #[test]
+  fn part1_test() {
+    let content = read_day_file(7, "test");
+
+    let mut pt = ProgramTree::empty();
+    pt.parse(&content).unwrap();
+
+    assert_eq!(pt.root().expect("").name, "tknk");
+  }
+
+  #[test]
+  fn test_vertex_targets() {
+    let content = read_day_file(7, "test");
+
+    let mut pt = ProgramTree::empty();
+    pt.parse(&content).unwrap();
+
+    assert_eq!(pt.vertex_targets(&String::from("fwft")).len(), 3);
+    assert_eq!(pt.vertex_targets(&String::from("qoyq")).len(), 0);
+  }
+
+  #[test]
+  fn part2_test() {
+    let content = read_day_file(7, "test");
+
+    let mut pt = ProgramTree::empty();
+    pt.parse(&content).unwrap();
+
+    assert_eq!(pt.vertex_total_weight(&String::from("ugml")), 251);
+    assert_eq!(pt.vertex_total_weight(&String::from("padx")), 243);
+    assert_eq!(pt.vertex_total_weight(&String::from("fwft")), 243);
+  }
+
+  #[test]
+  fn part2_balancing_weight() {
+    let content = read_day_file(7, "test");
+
+    let mut pt = ProgramTree::empty();
+    pt.parse(&content).unwrap();
+
+    assert_eq!(pt.balancing_weight(), Some(60));
+  }
+
+  #[test]
+  fn odd_one_out() {
+    assert_eq!(ProgramTree::odd_one_out(&[243, 251, 243]), Some(1));
+    assert_eq!(ProgramTree::odd_one_out(&[243, 243, 243]), None);
+    assert_eq!(ProgramTree::odd_one_out(&[243, 251]), None);
+  }
+
+  #[test]
+  fn towers_without_an_answer() {
+    let unsolvable = |why: &str| Err(SolveError::Unsolvable(String::from(why)));
+
+    let balanced = Day7.parse("pbga (66) -> xhth, ebii\nxhth (57)\nebii (57)").unwrap();
+    assert_eq!(Day7.try_part1(&balanced), Ok(Answer::from(String::from("pbga"))));
+    assert_eq!(Day7.try_part2(&balanced), unsolvable("the tower is already balanced"));
+
+    // a program on its own holds nothing up, so isn't the bottom of anything
+    for input in ["pbga (66)", "pbga (66) -> xhth\nxhth (57) -> pbga"].iter() {
+      let rootless = Day7.parse(input).unwrap();
+      assert_eq!(Day7.try_part1(&rootless), unsolvable("no program is at the bottom of the tower"));
+      assert_eq!(Day7.try_part2(&rootless), unsolvable("no program is at the bottom of the tower"));
+    }
+  }
+
+  #[test]
+  fn malformed_line() {
+    let mut pt = ProgramTree::empty();
+    let e = pt.parse("pbga (66)\nfwft (72) -> ktlj,, cntj").unwrap_err();
+    assert_eq!((e.line, e.column), (2, 19));
+    assert_eq!(e.expected, "a program name");
+  }
+}
@@ -0,0 +1,325 @@
+use regex::Regex;
+
+use std::collections::HashMap;
+use std::collections::hash_map::Keys;
+use std::hash::Hash;
+
+use observe::Observer;
+use parsing::{parse_as, ParseError};
+use solution::{Answer, Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+  type Input = Vec<Instruction>;
+
+  fn day(&self) -> u8 { return 8; }
+  fn title(&self) -> &'static str { return "I Heard You Like Registers"; }
+
+  fn parse(&self, input: &str) -> Result<Vec<Instruction>, ParseError> {
+    return RegisterMachine::parse(input);
+  }
+
+  fn part1(&self, input: &Vec<Instruction>) -> Answer {
+    let mut rt = RegisterMachine::from_instructions(input.clone());
+    rt.run();
+    return Answer::from(rt.largest_register_value());
+  }
+
+  fn part2(&self, input: &Vec<Instruction>) -> Answer {
+    let mut rt = RegisterMachine::from_instructions(input.clone());
+    rt.run();
+    return Answer::from(rt.high_mem());
+  }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Op {
+  INC(i32),
+  DEC(i32),
+}
+
+pub type Register = String;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ConditionOp {
+  GT(Register,i32),
+  LT(Register,i32),
+  EQ(Register,i32),
+  NEQ(Register,i32),
+  GTEQ(Register,i32),
+  LTEQ(Register,i32)
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Instruction {
+  target: Register,
+  op: Op,
+  condition: ConditionOp
+}
+
+impl Instruction {
+  // looks like `b inc 5 if a > 1'
+  pub fn parse(input: &str) -> Result<Instruction, ParseError> {
+    lazy_static! {
+      static ref PARSER: Regex = Regex::new(r"^\s*([a-z]+) ((?:inc)|(?:dec)) (-?\d+) if ([a-z]+) ([<>=!]+) (-?\d+)\s*$").unwrap(); 
+    }
+    let parsed = match PARSER.captures(input) {
+      Some(p) => p,
+      None => {
+        let leading = input.len() - input.trim_start().len();
+        return Err(ParseError::at(input, leading, input.trim(), "an instruction like `b inc 5 if a > 1'"));
+      }
+    };
+    // where each capture starts, for pointing at the ones that don't make sense
+    let at = |group: usize, expected: &str| {
+      let m = parsed.get(group).unwrap();
+      return ParseError::at(input, m.start(), m.as_str(), expected);
+    };
+
+    let register = &parsed[1];
+    let opcode;
+    let op_amt: i32 = parse_as::<i32>(&parsed[3]).map_err(|e| at(3, &e.expected))?;
+
+    if &parsed[2] == "inc" {
+      opcode = Op::INC(op_amt); 
+    } else {
+      opcode = Op::DEC(op_amt);
+    }
+
+    let cond_register = String::from(&parsed[4]);
+    let cond_amt: i32 = parse_as::<i32>(&parsed[6]).map_err(|e| at(6, &e.expected))?;
+    let condition;
+
+    match &parsed[5] {
+      ">" => condition = ConditionOp::GT(cond_register, cond_amt),
+      "<" => condition = ConditionOp::LT(cond_register, cond_amt),
+      ">=" => condition = ConditionOp::GTEQ(cond_register, cond_amt),
+      "<=" => condition = ConditionOp::LTEQ(cond_register, cond_amt),
+      "==" => condition = ConditionOp::EQ(cond_register, cond_amt),
+      "!=" => condition = ConditionOp::NEQ(cond_register, cond_amt),
+      _ => return Err(at(5, "one of >, <, >=, <=, == or !="))
+    }
+
+    return Ok(Instruction {
+      target: String::from(register),
+      op: opcode,
+      condition: condition
+    });
+  }
+}
+
+#[cfg(test)]
+mod instruction_tests {
+  use super::*;
+
+  #[test]
+  fn test_parser() {
+    let s = String::from("a inc 1 if b <= 2");
+    let i = Instruction::parse(&s).unwrap();
+    assert_eq!(i.target, String::from("a"));
+    assert_eq!(i.op, Op::INC(1));
+    assert_eq!(i.condition, ConditionOp::LTEQ(String::from("b"), 2));
+  }
+
+  #[test]
+  fn test_parser_malformed() {
+    let e = Instruction::parse("a inc 1 if b =< 2").unwrap_err();
+    assert_eq!(e.column, 14);
+    assert_eq!(e.text, "=<");
+
+    let e = Instruction::parse("a inc 99999999999 if b < 2").unwrap_err();
+    assert_eq!(e.column, 7);
+    assert_eq!(e.expected, "a value of type i32");
+
+    let e = Instruction::parse("  a jump 1").unwrap_err();
+    assert_eq!(e.column, 3);
+  }
+
+  #[test]
+  fn test_sanity() {
+    let r = Regex::new(r"((?:inc)|(?:dec))").unwrap(); 
+    assert!(r.is_match("inc"));
+    assert!(r.is_match("dec"));
+    assert!(!r.is_match("asdf"));
+
+  }
+}
+
+#[derive(Debug)]
+pub struct HashMapWithDefault<K,V> 
+  where K: Hash + PartialEq + Eq {
+  hash: HashMap<K,V>,
+  default: V
+}
+
+impl<K: PartialEq + Eq + Hash, V> HashMapWithDefault<K,V> {
+  pub fn new(default: V) -> HashMapWithDefault<K,V> {
+    return HashMapWithDefault { hash: HashMap::new(), default: default };
+  }
+
+  pub fn insert(&mut self, key: K, value: V) {
+    self.hash.insert(key, value);
+  }
+
+  pub fn get(&self, key: K) -> &V {
+    return self.hash.get(&key).unwrap_or(&self.default);
+  }
+
+  pub fn keys(&self) -> Keys<'_, K, V> {
+    return self.hash.keys();
+  }
+
+  pub fn clear(&mut self) {
+    self.hash.clear();
+  }
+}
+
+
+#[derive(Debug)]
+pub struct RegisterMachine {
+  instructions: Vec<Instruction>,
+  registers: HashMapWithDefault<String, i32>,
+  high_mem: i32
+}
+
+
+impl RegisterMachine {
+  pub fn new(input: &str) -> Result<RegisterMachine, ParseError> {
+    let instructions = RegisterMachine::parse(input)?;
+    return Ok(RegisterMachine::from_instructions(instructions));
+  }
+
+  pub fn from_instructions(instructions: Vec<Instruction>) -> RegisterMachine {
+    return RegisterMachine { instructions: instructions, registers: HashMapWithDefault::new(0), high_mem: 0 };
+  }
+
+  pub fn run(&mut self) {
+    self.run_observed(&mut ());
+  }
+
+  // each instruction is an event, whether or not its condition holds
+  pub fn run_observed<O: Observer<RegisterMachine, Instruction>>(&mut self, observer: &mut O) {
+    let instructions = self.instructions.clone();
+    for i in &instructions {
+      observer.before(self, i);
+      self.execute(i);
+      observer.after(self, i);
+    }
+  }
+
+  fn execute(&mut self, i: &Instruction) {
+    let cond_status: bool;
+    let condition = i.condition.to_owned();
+    match condition {
+      ConditionOp::GT(reg, amt) => cond_status = self.register_value(&reg) > amt,
+      ConditionOp::LT(reg, amt) => cond_status = self.register_value(&reg) < amt,
+      ConditionOp::GTEQ(reg, amt) => cond_status = self.register_value(&reg) >= amt,
+      ConditionOp::LTEQ(reg, amt) => cond_status = self.register_value(&reg) <= amt,
+      ConditionOp::EQ(reg, amt) => cond_status = self.register_value(&reg) == amt,
+      ConditionOp::NEQ(reg, amt) => cond_status = self.register_value(&reg) != amt
+    }
+
+    if !cond_status { return; }
+
+    let target = i.target.to_owned();
+    let current_value = self.register_value(&target);
+
+    match i.op {
+      Op::INC(amt) => { self.registers.insert(target, current_value + amt); }
+      Op::DEC(amt) => { self.registers.insert(target, current_value - amt); }
+    }
+
+    let high = self.largest_register_value();
+    if high > self.high_mem {
+      self.high_mem = high;
+    }
+  }
+
+  // the highest value held in any register at any point during the run
+  pub fn high_mem(&self) -> i32 {
+    return self.high_mem;
+  }
+
+  pub fn largest_register_value(&self) -> i32 {
+    return self.register_value(&self.largest_register());
+  }
+
+  pub fn largest_register(&self) -> String {
+    let mut max = 0;
+    let mut reg_name = String::new();
+    for key in self.register_names() {
+      let value = self.register_value(key);
+      if value > max {
+        max = value;
+        reg_name = key.to_owned();
+      }
+    }
+
+    return reg_name;
+  }
+
+  pub fn register_value(&self, name: &str) -> i32 {
+    return *self.registers.get(name.to_owned());
+  }
+
+  fn register_names(&self) -> Keys<'_, String, i32> {
+    return self.registers.keys();
+  }
+
+  // parse source to instruction objects, one per line
+  pub fn parse(source: &str) -> Result<Vec<Instruction>, ParseError> {
+    let mut instructions = vec![];
+    for (idx, line) in source.lines().enumerate() {
+      let i = Instruction::parse(line).map_err(|e| e.on_line(idx + 1))?;
+      instructions.push(i);
+    }
+    return Ok(instructions);
+  }
+}
+
+
+#[cfg(test)]
+mod register_machine_tests {
+  use super::*;
+  use input::read_day_file;
+
+  #[test]
+  fn part1_ex() {
+    let content = read_day_file(8, "test");
+
+    let mut rt = RegisterMachine::new(&content).unwrap();
+    rt.run();
+    assert_eq!(String::from("a"), rt.largest_register());
+    assert_eq!(1, rt.largest_register_value());
+
+  }
+
+  #[test]
+  fn part2_ex() {
+    let content = read_day_file(8, "test");
+
+    let mut rt = RegisterMachine::new(&content).unwrap();
+    rt.run();
+    assert_eq!(String::from("a"), rt.largest_register());
+    assert_eq!(1, rt.largest_register_value());
+    assert_eq!(10, rt.high_mem);
+
+  }
+  #[test]
+  fn observed_instructions() {
+    use observe::Observer;
+
+    // the value of `a` either side of each instruction
+    struct Watch { a: Vec<(i32, i32)> }
+    impl Observer<RegisterMachine, Instruction> for Watch {
+      fn before(&mut self, m: &RegisterMachine, _i: &Instruction) { self.a.push((m.register_value("a"), 0)); }
+      fn after(&mut self, m: &RegisterMachine, _i: &Instruction) { self.a.last_mut().unwrap().1 = m.register_value("a"); }
+    }
+
+    let mut watch = Watch { a: vec![] };
+    let mut rt = RegisterMachine::new(&read_day_file(8, "test")).unwrap();
+    rt.run_observed(&mut watch);
+    assert_eq!(watch.a, vec![(0, 0), (0, 1), (1, 1), (1, 1)]);
+  }
+}
@@ -0,0 +1,156 @@
+use parsing::ParseError;
+use solution::{Answer, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+  // the stream is scored as it's parsed, so there's nothing left over for the parts to do
+  type Input = (i32, i32);
+
+  fn day(&self) -> u8 { return 9; }
+  fn title(&self) -> &'static str { return "Stream Processing"; }
+
+  fn parse(&self, input: &str) -> Result<(i32, i32), ParseError> {
+    return solve(input);
+  }
+
+  fn part1(&self, input: &(i32, i32)) -> Answer {
+    return Answer::from(input.0);
+  }
+
+  fn part2(&self, input: &(i32, i32)) -> Answer {
+    return Answer::from(input.1);
+  }
+}
+
+// The group score and the number of garbage characters. Each character is kept along with its
+// offset into the input, so anything unexpected can be pointed at.
+pub fn solve(input: &str) -> Result<(i32, i32), ParseError> {
+  let leading = input.len() - input.trim_start().len();
+  let mut stack_a : Vec<(usize, char)> = input.trim().char_indices().map(|(i, c)| (i + leading, c)).collect();
+  let mut stack_b : Vec<(usize, char)> = vec![];
+
+  // Make sequence cancel-free
+  while !stack_a.is_empty() {
+    let ptr = stack_a.remove(0);
+    if ptr.1 == '!' {
+      if stack_a.is_empty() {
+        return Err(ParseError::at(input, ptr.0, "!", "a character for `!' to cancel"));
+      }
+      stack_a.remove(0); // burn the next character, discard both
+    } else {
+      stack_b.push(ptr); // keep the character
+    }
+  }
+
+  stack_a.clear();
+
+  // make sequence garbage free
+  let mut garbage_mode : bool = false;
+  let mut total_garbage_chars = 0;
+  while !stack_b.is_empty() {
+    let ptr = stack_b.remove(0);
+    if garbage_mode {
+      if ptr.1 == '>' { 
+        garbage_mode = false; // if we see the end of garbage, reset the flag.
+      }
+      else {
+        total_garbage_chars += 1;
+      }
+    } else {
+      if ptr.1 == '<' { // if we see the beginning of garbage, set the flag.
+        garbage_mode = true;
+      } else {
+        stack_a.push(ptr);
+      }
+    }
+  }
+
+  stack_b.clear();
+
+  // now count the groups, since all the garbage is removed.
+  let mut total = 0;
+  let mut depth = 0;
+  while !stack_a.is_empty() {
+    let (offset, ptr) = stack_a.remove(0);
+    if ptr == '{' {
+      depth += 1;
+    } else if ptr == '}' {
+      total += depth;
+      depth -= 1;
+    } else if ptr == ',' || ptr == '\n' || ptr == '\r' {
+      // do nothing, these mean nothing.
+    } else {
+      return Err(ParseError::at(input, offset, &ptr.to_string(), "a group, garbage or `,'"));
+    }
+  }
+
+  return Ok((total, total_garbage_chars));
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use input::read_day_file;
+
+  #[test]
+  fn test_1() {
+    let content = read_day_file(9, "test1");
+    assert_eq!(solve(&content), Ok((1,0)));
+  }
+
+  #[test]
+  fn test_2() {
+    let content = read_day_file(9, "test2");
+    assert_eq!(solve(&content), Ok((6,0)));
+  }
+
+  #[test]
+  fn test_3() {
+    let content = read_day_file(9, "test3");
+    assert_eq!(solve(&content), Ok((5,0)));
+  }
+
+  #[test]
+  fn test_4() {
+    let content = read_day_file(9, "test4");
+    assert_eq!(solve(&content), Ok((16,0)));
+  }
+
+  #[test]
+  fn test_5() {
+    let content = read_day_file(9, "test5");
+    assert_eq!(solve(&content), Ok((1,4)));
+  }
+
+  #[test]
+  fn test_6() {
+    let content = read_day_file(9, "test6");
+    assert_eq!(solve(&content), Ok((9,8)));
+  }
+
+  #[test]
+  fn test_7() {
+    let content = read_day_file(9, "test7");
+    assert_eq!(solve(&content), Ok((9,0)));
+  }
+
+  #[test]
+  fn test_8() {
+    let content = read_day_file(9, "test8");
+    assert_eq!(solve(&content), Ok((3,17)));
+  }
+
+  #[test]
+  fn stray_character() {
+    let e = solve("{{<a>},x}").unwrap_err();
+    assert_eq!((e.line, e.column), (1, 8));
+    assert_eq!(e.text, "x");
+  }
+
+  #[test]
+  fn dangling_cancel() {
+    let e = solve("  {<!").unwrap_err();
+    assert_eq!(e.column, 5);
+  }
+}
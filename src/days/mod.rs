@@ -0,0 +1,53 @@
+// One module per puzzle, see http://adventofcode.com/2017
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+
+use solution::Solver;
+
+// every day in order, `all()[0]` is day 1
+pub fn all() -> Vec<&'static dyn Solver> {
+  return vec![
+    &day1::Day1, &day2::Day2, &day3::Day3, &day4::Day4, &day5::Day5, &day6::Day6,
+    &day7::Day7, &day8::Day8, &day9::Day9, &day10::Day10, &day11::Day11, &day12::Day12,
+    &day13::Day13, &day14::Day14, &day15::Day15, &day16::Day16, &day17::Day17
+  ];
+}
+
+pub fn find(day: u8) -> Option<&'static dyn Solver> {
+  return all().into_iter().find(|s| s.day() == day);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn registry_is_in_day_order() {
+    for (idx, solver) in all().iter().enumerate() {
+      assert_eq!(solver.day() as usize, idx + 1);
+    }
+  }
+
+  #[test]
+  fn find_by_day() {
+    assert_eq!(find(7).expect("day 7 is registered").title(), "Recursive Circus");
+    assert!(find(0).is_none());
+    assert!(find(26).is_none());
+  }
+}
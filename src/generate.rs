@@ -0,0 +1,442 @@
+// Random, valid puzzle inputs for every day, for stress testing and benchmarking the parsers and
+// solvers at sizes the examples don't reach. The same seed always gives the same input.
+//
+// `size` is roughly how big the input gets, in whatever unit suits the day:
+//
+//     day 1   digits                  day 10  lengths
+//     day 2   rows                    day 11  steps
+//     day 3   the largest target      day 12  programs
+//     day 4   passphrases             day 13  layers
+//     day 5   jump offsets            day 14  (ignored)
+//     day 6   memory banks            day 15  (ignored)
+//     day 7   programs in the tower   day 16  dance moves
+//     day 8   instructions            day 17  the largest step
+//     day 9   groups and garbage
+//
+// Beyond parsing, the inputs are built so each day's solver has an answer: every spreadsheet row
+// has exactly one evenly divisible pair, the tower has exactly one wrong weight, and there's a
+// delay that gets through the firewall.
+
+use std::collections::{BTreeSet, HashSet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+  pub size: usize,
+  pub seed: u64
+}
+
+// `None` for a day with no puzzle
+pub fn generate(day: u8, options: &Options) -> Option<String> {
+  let mut rng = Rng::new(options.seed);
+  let size = options.size.max(1);
+
+  let input = match day {
+    1 => captcha(&mut rng, size),
+    2 => spreadsheet(&mut rng, size),
+    3 => rng.range(1, size as i64).to_string(),
+    4 => passphrases(&mut rng, size),
+    5 => jumps(&mut rng, size),
+    6 => banks(&mut rng, size),
+    7 => tower(&mut rng, size),
+    8 => registers(&mut rng, size),
+    9 => stream(&mut rng, size),
+    10 => lengths(&mut rng, size),
+    11 => hex_path(&mut rng, size),
+    12 => pipes(&mut rng, size),
+    13 => firewall(&mut rng, size),
+    14 => word(&mut rng, 8),
+    15 => format!("Generator A starts with {}\nGenerator B starts with {}", rng.range(1, 2147483646), rng.range(1, 2147483646)),
+    16 => dance(&mut rng, size),
+    17 => rng.range(1, size as i64).to_string(),
+    _ => return None
+  };
+
+  return Some(input + "\n");
+}
+
+// splitmix64, small and plenty random enough for test inputs
+pub struct Rng {
+  state: u64
+}
+
+impl Rng {
+  pub fn new(seed: u64) -> Rng {
+    return Rng { state: seed };
+  }
+
+  pub fn next_u64(&mut self) -> u64 {
+    self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = self.state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    return z ^ (z >> 31);
+  }
+
+  // anywhere from `low` to `high`, inclusive
+  pub fn range(&mut self, low: i64, high: i64) -> i64 {
+    assert!(low <= high, "an empty range, from {} down to {}", low, high);
+    let span = (high - low) as u64 + 1;
+    return low + (self.next_u64() % span) as i64;
+  }
+
+  pub fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+    return items[self.range(0, items.len() as i64 - 1) as usize];
+  }
+
+  pub fn shuffle<T>(&mut self, items: &mut [T]) {
+    for i in (1..items.len()).rev() {
+      let j = self.range(0, i as i64) as usize;
+      items.swap(i, j);
+    }
+  }
+}
+
+fn word(rng: &mut Rng, length: usize) -> String {
+  return (0..length).map(|_| rng.range(b'a' as i64, b'z' as i64) as u8 as char).collect();
+}
+
+fn join<T: ToString>(items: &[T], separator: &str) -> String {
+  return items.iter().map(|i| i.to_string()).collect::<Vec<String>>().join(separator);
+}
+
+fn captcha(rng: &mut Rng, size: usize) -> String {
+  // a small range of digits so neighbours match often enough to be interesting
+  return (0..size).map(|_| (b'0' + rng.range(0, 3) as u8 * 3) as char).collect();
+}
+
+// values between `low` and `2 * low` never divide each other, so one multiple of one of them
+// makes the only divisible pair, as long as it doesn't happen to divide by another
+fn spreadsheet(rng: &mut Rng, size: usize) -> String {
+  let mut rows = vec![];
+
+  while rows.len() < size {
+    let width = rng.range(2, 16) as usize;
+    let low = rng.range(20, 500);
+
+    let mut cells = BTreeSet::new();
+    while cells.len() < width - 1 {
+      cells.insert(rng.range(low, 2 * low - 1));
+    }
+    let mut cells: Vec<i64> = cells.into_iter().collect();
+    let multiple = rng.pick(&cells) * rng.range(2, 9);
+    cells.push(multiple);
+
+    let mut pairs = 0;
+    for a in &cells {
+      for b in &cells {
+        if a != b && a % b == 0 { pairs += 1; }
+      }
+    }
+    if pairs != 1 { continue; }
+
+    rng.shuffle(&mut cells);
+    rows.push(join(&cells, "\t"));
+  }
+
+  return rows.join("\n");
+}
+
+fn passphrases(rng: &mut Rng, size: usize) -> String {
+  let mut lines = vec![];
+  for _ in 0..size {
+    let count = rng.range(2, 10);
+    // a handful of letters, so repeats and anagrams turn up
+    let words: Vec<String> = (0..count).map(|_| {
+      let length = rng.range(2, 5);
+      (0..length).map(|_| rng.range(b'a' as i64, b'f' as i64) as u8 as char).collect()
+    }).collect();
+    lines.push(words.join(" "));
+  }
+  return lines.join("\n");
+}
+
+fn jumps(rng: &mut Rng, size: usize) -> String {
+  // mostly backwards, never before the start
+  let offsets: Vec<i64> = (0..size).map(|i| rng.range(-(i as i64), 2)).collect();
+  return join(&offsets, "\n");
+}
+
+fn banks(rng: &mut Rng, size: usize) -> String {
+  let blocks: Vec<i64> = (0..size).map(|_| rng.range(0, 15)).collect();
+  return join(&blocks, "\t");
+}
+
+struct Program {
+  name: String,
+  weight: i64,
+  children: Vec<usize>
+}
+
+// Builds a balanced tower top down, each program's total split evenly between three to five
+// children, then knocks one program's weight off.
+fn tower(rng: &mut Rng, size: usize) -> String {
+  let mut programs = vec![];
+  let mut names = HashSet::new();
+  // enough for the root's children however many it picks, so there's always one to get wrong
+  let mut budget = size.max(6) - 1;
+
+  let total = rng.range(1000000, 100000000);
+  build_tower(rng, total, &mut budget, &mut programs, &mut names);
+
+  let odd = rng.range(1, programs.len() as i64 - 1) as usize;
+  let delta = rng.range(1, 9);
+  if programs[odd].weight > delta && rng.range(0, 1) == 0 {
+    programs[odd].weight -= delta;
+  } else {
+    programs[odd].weight += delta;
+  }
+
+  let mut lines = vec![];
+  for p in &programs {
+    if p.children.is_empty() {
+      lines.push(format!("{} ({})", p.name, p.weight));
+    } else {
+      let children: Vec<&str> = p.children.iter().map(|&c| programs[c].name.as_str()).collect();
+      lines.push(format!("{} ({}) -> {}", p.name, p.weight, children.join(", ")));
+    }
+  }
+  rng.shuffle(&mut lines);
+
+  return lines.join("\n");
+}
+
+// the index of a new program weighing `total` all told
+fn build_tower(rng: &mut Rng, total: i64, budget: &mut usize, programs: &mut Vec<Program>, names: &mut HashSet<String>) -> usize {
+  let mut name = word(rng, 4);
+  while names.contains(&name) {
+    let length = rng.range(4, 7) as usize;
+    name = word(rng, length);
+  }
+  names.insert(name.clone());
+
+  let idx = programs.len();
+  programs.push(Program { name: name, weight: total, children: vec![] });
+
+  // the root always gets children, so there's somewhere for the wrong weight to go
+  let count = rng.range(3, 5);
+  let expand = idx == 0 || rng.range(0, 2) > 0;
+  if !expand || *budget < count as usize || total < 20 {
+    return idx;
+  }
+
+  let low = total / (count + 3);
+  let high = (total - 1) / count;
+  let child_total = rng.range(low.max(1), high);
+
+  *budget -= count as usize;
+  programs[idx].weight = total - count * child_total;
+  for _ in 0..count {
+    let child = build_tower(rng, child_total, budget, programs, names);
+    programs[idx].children.push(child);
+  }
+
+  return idx;
+}
+
+fn registers(rng: &mut Rng, size: usize) -> String {
+  let mut names = vec![];
+  for _ in 0..(size / 10).max(2) {
+    let length = rng.range(1, 3) as usize;
+    names.push(word(rng, length));
+  }
+
+  let names: Vec<&str> = names.iter().map(|n| n.as_str()).collect();
+
+  let mut lines = vec![];
+  for _ in 0..size {
+    let target = rng.pick(&names);
+    let op = rng.pick(&["inc", "dec"]);
+    let amount = rng.range(-1000, 1000);
+    let source = rng.pick(&names);
+    let condition = rng.pick(&[">", "<", ">=", "<=", "==", "!="]);
+    let compare = rng.range(-1000, 1000);
+    lines.push(format!("{} {} {} if {} {} {}", target, op, amount, source, condition, compare));
+  }
+  return lines.join("\n");
+}
+
+// one outer group holding `size` groups and bits of garbage, nested at random
+fn stream(rng: &mut Rng, size: usize) -> String {
+  let mut out = String::from("{");
+  let mut budget = size;
+  let mut first = true;
+
+  while budget > 0 {
+    if !first { out.push(','); }
+    first = false;
+    stream_item(rng, &mut budget, 1, &mut out);
+  }
+
+  out.push('}');
+  return out;
+}
+
+fn stream_item(rng: &mut Rng, budget: &mut usize, depth: usize, out: &mut String) {
+  *budget = budget.saturating_sub(1);
+
+  if rng.range(0, 2) == 0 {
+    out.push('<');
+    for _ in 0..rng.range(0, 8) {
+      let c = rng.pick(&['a', 'e', 'i', 'o', 'u', '{', '}', '<', ',', '"', '\'', '!']);
+      out.push(c);
+      if c == '!' {
+        // anything can be cancelled, even the end of the garbage
+        out.push(rng.pick(&['!', '>', '<', '{', 'x']));
+      }
+    }
+    out.push('>');
+  } else {
+    out.push('{');
+    let children = if depth > 20 { 0 } else { rng.range(0, 3) };
+    for i in 0..children {
+      if *budget == 0 { break; }
+      if i > 0 { out.push(','); }
+      stream_item(rng, budget, depth + 1, out);
+    }
+    out.push('}');
+  }
+}
+
+fn lengths(rng: &mut Rng, size: usize) -> String {
+  let lengths: Vec<i64> = (0..size).map(|_| rng.range(0, 255)).collect();
+  return join(&lengths, ",");
+}
+
+fn hex_path(rng: &mut Rng, size: usize) -> String {
+  let steps: Vec<&str> = (0..size).map(|_| rng.pick(&["n", "ne", "se", "s", "sw", "nw"])).collect();
+  return steps.join(",");
+}
+
+fn pipes(rng: &mut Rng, size: usize) -> String {
+  let mut neighbours = vec![BTreeSet::new(); size];
+  for i in 0..size {
+    for _ in 0..rng.range(0, 2) {
+      let j = rng.range(0, size as i64 - 1) as usize;
+      neighbours[i].insert(j);
+      neighbours[j].insert(i);
+    }
+  }
+
+  let mut lines = vec![];
+  for (i, n) in neighbours.iter_mut().enumerate() {
+    // a program connected to nothing else lists itself
+    if n.is_empty() { n.insert(i); }
+    let n: Vec<usize> = n.iter().cloned().collect();
+    lines.push(format!("{} <-> {}", i, join(&n, ", ")));
+  }
+  return lines.join("\n");
+}
+
+// every layer is picked to miss a packet sent at some secret delay, so part 2 always finishes
+fn firewall(rng: &mut Rng, size: usize) -> String {
+  let delay = rng.range(1, 10000);
+  let mut lines = vec![];
+  let mut depth = 0;
+
+  while lines.len() < size {
+    for _ in 0..20 {
+      let range = rng.range(2, 20);
+      if (delay + depth) % (2 * (range - 1)) != 0 {
+        lines.push(format!("{}: {}", depth, range));
+        break;
+      }
+    }
+    depth += rng.range(1, 3);
+  }
+
+  return lines.join("\n");
+}
+
+fn dance(rng: &mut Rng, size: usize) -> String {
+  let programs = ['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p'];
+  let mut moves = vec![];
+  for _ in 0..size {
+    match rng.range(0, 2) {
+      0 => moves.push(format!("s{}", rng.range(1, 15))),
+      1 => moves.push(format!("x{}/{}", rng.range(0, 15), rng.range(0, 15))),
+      _ => {
+        let a = rng.pick(&programs);
+        let mut b = rng.pick(&programs);
+        while b == a { b = rng.pick(&programs); }
+        moves.push(format!("p{}/{}", a, b));
+      }
+    }
+  }
+  return moves.join(",");
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use days;
+  use days::day15::Day15;
+  use days::day17::Day17;
+  use days::day7::ProgramTree;
+  use solution::Solution;
+
+  fn options(size: usize, seed: u64) -> Options {
+    return Options { size: size, seed: seed };
+  }
+
+  #[test]
+  fn same_seed_same_input() {
+    for day in 1..18 {
+      assert_eq!(generate(day, &options(30, 7)), generate(day, &options(30, 7)));
+    }
+    assert!(generate(7, &options(30, 7)) != generate(7, &options(30, 8)));
+  }
+
+  #[test]
+  fn no_such_day() {
+    assert_eq!(generate(0, &options(10, 1)), None);
+    assert_eq!(generate(26, &options(10, 1)), None);
+  }
+
+  #[test]
+  fn range_stays_inclusive() {
+    let mut rng = Rng::new(3);
+    let seen: BTreeSet<i64> = (0..200).map(|_| rng.range(-2, 2)).collect();
+    assert_eq!(seen.into_iter().collect::<Vec<i64>>(), vec![-2, -1, 0, 1, 2]);
+    assert_eq!(rng.range(7, 7), 7);
+  }
+
+  #[test]
+  #[should_panic(expected = "an empty range, from 1 down to 0")]
+  fn range_turns_down_empty_ranges() {
+    Rng::new(3).range(1, 0);
+  }
+
+  // 15 and 17 take too long to solve here, they only need to parse
+  #[test]
+  fn every_day_solves_what_it_generates() {
+    for &size in &[1, 2, 3, 4, 5, 40] {
+      for seed in 0..3 {
+        for solver in days::all() {
+          let input = generate(solver.day(), &options(size, seed)).unwrap();
+          match solver.day() {
+            15 => assert!(Day15.parse(&input).is_ok()),
+            17 => assert!(Day17.parse(&input).is_ok()),
+            _ => assert!(solver.solve_both(&input).is_ok(), "day {} size {} seed {}:\n{}", solver.day(), size, seed, input)
+          }
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn tower_has_one_wrong_weight() {
+    for seed in 0..10 {
+      let input = generate(7, &options(60, seed)).unwrap();
+      let mut pt = ProgramTree::empty();
+      pt.parse(&input).unwrap();
+      assert!(pt.balancing_weight().is_some(), "seed {}:\n{}", seed, input);
+    }
+  }
+
+  #[test]
+  fn sizes() {
+    assert_eq!(generate(1, &options(25, 1)).unwrap().trim().len(), 25);
+    assert_eq!(generate(2, &options(25, 1)).unwrap().lines().count(), 25);
+    assert_eq!(generate(16, &options(25, 1)).unwrap().split(',').count(), 25);
+  }
+}
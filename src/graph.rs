@@ -1,39 +1,12 @@
-use std::fs::File;
-use std::io::prelude::*;
 use std::collections::HashSet;
 use std::hash::Hash;
 use std::str::FromStr;
 
-fn parse_as<T : FromStr>(input: &String) -> T {
-  let parsed : Result<T, T::Err> = input.trim().parse();
-  match parsed {
-    Ok(d) => return d,
-    Err(_) => panic!("Failed to parse {}", input)
-  }
-}
-
-fn main() {
-  println!("Advent of Code Day 12");
-  println!("");
-  println!("http://adventofcode.com/2017/day/12");
-  println!("");
-
-  let mut file = File::open("data/day12/input").expect("file not found");
-  let mut content = String::new();
-  file.read_to_string(&mut content).expect("Something went wrong reading input file");
-
-  solve(&content);
-}
-
-fn solve(input: &String) {
-  let g = Graph::parse(input);
-  println!("Part 1: {}", g.spanning_set_for(0).len());
-
-  println!("Part 2: {}", g.number_of_groups());
-}
+use parsing::{fields, parse_as, ParseError};
 
 // undirected graph
-struct Graph<T> {
+#[derive(Debug)]
+pub struct Graph<T> {
   vertices: HashSet<T>,
   edges: HashSet<(T,T)>
 }
@@ -46,19 +19,27 @@ impl<T : PartialEq + Eq + Hash + FromStr + Copy> Graph<T> {
     }
   }
 
-  pub fn parse(input: &String) -> Graph<T> {
+  // lines look like `2 <-> 0, 3, 4`
+  pub fn parse(input: &str) -> Result<Graph<T>, ParseError> {
     let mut g = Graph::empty();
 
-    for line in input.lines() {
-      let mut split_str = line.trim().split("<->");
-      let vertex_str = &String::from(split_str.next().unwrap());
-      let vertex = parse_as::<T>(vertex_str);
-      let edge_list = split_str.next().unwrap();
+    for (idx, line) in input.lines().enumerate() {
+      if line.trim().is_empty() { continue; }
+
+      let arrow = match line.find("<->") {
+        Some(a) => a,
+        None => {
+          let leading = line.len() - line.trim_start().len();
+          return Err(ParseError::at(line, leading, line.trim(), "a line like `2 <-> 0, 3, 4'").on_line(idx + 1));
+        }
+      };
+      let vertex = parse_as::<T>(&line[..arrow]).map_err(|e| e.on_line(idx + 1))?;
+      let edges_column = line[..arrow].chars().count() + 4;
 
       g.add_vertex(vertex);
 
-      for edge in edge_list.split(",") {
-        let target = parse_as::<T>(&String::from(edge));
+      for (column, edge) in fields(&line[arrow + 3..], ',') {
+        let target = parse_as::<T>(edge).map_err(|e| e.on_line(idx + 1).from_column(edges_column + column - 1))?;
         // we force it so we can build the graph in one pass,
         // if the input is bad, we'll get dangling elements
         // on the graph.
@@ -66,18 +47,16 @@ impl<T : PartialEq + Eq + Hash + FromStr + Copy> Graph<T> {
       }
     }
 
-    return g;
+    return Ok(g);
   }
+
   // the set of all vertices in G that are in the spanning
   // tree rooted at v
   pub fn spanning_set_for(&self, v: T) -> HashSet<T> {
     let mut spanning_set = HashSet::new();
     let mut search_set = self.all_connected(v);
 
-    while !search_set.is_empty() {
-      // unwrap safe because of the above check
-      let c = search_set.pop().unwrap();
-
+    while let Some(c) = search_set.pop() {
       spanning_set.insert(c);
 
       for connection in self.all_connected(c) {
@@ -143,7 +122,6 @@ impl<T : PartialEq + Eq + Hash + FromStr + Copy> Graph<T> {
   }
 }
 
-
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -175,33 +153,21 @@ mod tests {
   }
 
   #[test]
-  fn test_ex1_spanning() {
-    let mut file = File::open("data/day12/test").expect("file not found");
-    let mut content = String::new();
-    file.read_to_string(&mut content).expect("Something went wrong reading test file");
-
-    let g : Graph<i32> = Graph::parse(&content);
-    let spanning = g.spanning_set_for(0);
-
-    assert!(spanning.contains(&0));
-    assert!(spanning.contains(&2));
-    assert!(spanning.contains(&3));
-    assert!(spanning.contains(&4));
-    assert!(spanning.contains(&5));
-    assert!(spanning.contains(&6));
-
-    assert!(!spanning.contains(&1));
+  #[should_panic]
+  fn test_add_edge_missing_vertex() {
+    let mut g = Graph::empty();
+
+    g.add_vertex(0);
+    g.add_edge(0,1);
   }
 
   #[test]
-  fn test_ex1_groups() {
-    let mut file = File::open("data/day12/test").expect("file not found");
-    let mut content = String::new();
-    file.read_to_string(&mut content).expect("Something went wrong reading test file");
-
-    let g : Graph<i32> = Graph::parse(&content);
+  fn test_parse_malformed() {
+    let e = Graph::<i32>::parse("0 <-> 2\n2 <-> 0, x, 4").unwrap_err();
+    assert_eq!((e.line, e.column), (2, 10));
+    assert_eq!(e.text, "x");
 
-    assert_eq!(g.number_of_groups(), 2);
+    let e = Graph::<i32>::parse("0 <-> 2\n\n  2 -> 0").unwrap_err();
+    assert_eq!((e.line, e.column), (3, 3));
   }
-
 }
@@ -0,0 +1,445 @@
+// Grids shared between days: integer points on the plane (day 3), and the bit-packed disk from
+// day 14.
+
+use std::fmt;
+use std::fmt::{Formatter,Debug};
+
+use knot_hash;
+
+// the four ways along the axes, with y increasing upwards
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Direction {
+  Right,
+  Up,
+  Left,
+  Down
+}
+
+impl Direction {
+  pub fn to_vector(self) -> (i64, i64) {
+    match self {
+      Direction::Right => (1,0),
+      Direction::Up => (0,1),
+      Direction::Left => (-1,0),
+      Direction::Down => (0,-1)
+    }
+  }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Point {
+  pub x: i64,
+  pub y: i64
+}
+
+impl Point {
+  pub fn new(x: i64, y: i64) -> Point {
+    return Point { x: x, y: y };
+  }
+
+  pub fn neighborhood(&self, size: i64) -> Vec<Point> {
+    let mut result = vec![];
+
+    // for loops aren't inclusive... weird.
+    for i in (-size)..(size+1) {
+      for j in (-size)..(size+1) {
+        if i != 0 || j != 0 {
+          result.push(Point::new(self.x + i, self.y + j));
+        }
+      }
+    }
+
+    return result;
+  }
+
+  pub fn step(&self, dir: Direction) -> Point {
+    let (dx, dy) = dir.to_vector();
+    return Point::new(self.x + dx, self.y + dy);
+  }
+
+  // the 4 points sharing a side, right, up, left, down
+  pub fn adjacent(&self) -> Vec<Point> {
+    return vec![
+      Point::new(self.x + 1, self.y),
+      Point::new(self.x, self.y + 1),
+      Point::new(self.x - 1, self.y),
+      Point::new(self.x, self.y - 1)
+    ];
+  }
+}
+
+impl fmt::Display for Point {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "({}, {})", self.x, self.y)
+  }
+}
+
+impl fmt::Debug for Point {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "({}, {})", self.x, self.y)
+  }
+}
+
+#[derive(PartialEq, Eq)]
+pub struct BitBoard {
+  content: Vec<Vec<u8>>
+}
+
+impl BitBoard {
+  pub fn new(content: Vec<Vec<u8>>) -> BitBoard {
+    return BitBoard { content: content };
+  }
+
+  // one row per knot hash of `<key>-<row>`, 128 rows of 128 bits.
+  pub fn from_hashes(input: &str) -> BitBoard {
+    let mut blocks = vec![];
+    let source = input.trim();
+    for i in 0..128 {
+      blocks.push(knot_hash::hash(&format!("{}-{}", source, i)));
+    }
+
+    return BitBoard::new(blocks);
+  }
+
+  pub fn live_squares(&self) -> u32 {
+    let mut sum = 0;
+    for block in &self.content {
+      for cell in block {
+        sum += cell.count_ones();
+      }
+    }
+    return sum;
+  }
+
+  // Coord system is in the fourth quadrant because it's easier that way.
+  // That means that low y values = top of field, high = bottom.
+  pub fn is_set(&self, x: usize, y: usize) -> bool {
+    let chunk = x / 8;
+
+    if y >= self.content.len() { return false; }
+    if chunk >= self.content[y].len() { return false; }
+
+    return (self.content[y][chunk] & BitBoard::bit(x)) > 0;
+  }
+
+  // flips the bit specified if it's in range, nothing otherwise
+  pub fn flip(&mut self, x: usize, y: usize) {
+    let chunk = x / 8;
+
+    if y >= self.content.len() { return; }
+    if chunk >= self.content[y].len() { return; }
+
+    self.content[y][chunk] ^= BitBoard::bit(x);
+  }
+
+  pub fn is_unset(&self, x: usize, y: usize) -> bool {
+    return !self.is_set(x,y);
+  }
+
+  pub fn turn_off(&mut self, x: usize, y: usize) {
+    if self.is_set(x,y) { self.flip(x,y); }
+  }
+
+  pub fn turn_on(&mut self, x: usize, y: usize) {
+    if !self.is_set(x,y) { self.flip(x,y); }
+  }
+
+  // if the initial point is set, flip the region around it to 0
+  // otherwise do nothing
+  pub fn flip_region(&mut self, x: usize, y: usize) {
+    if self.is_unset(x,y) { return; }
+    let mut stack = vec![(x,y)];
+    let mut seen = vec![];
+
+    while let Some((x0,y0)) = stack.pop() {
+      seen.push((x0,y0));
+
+      // there's no 'inclusive range' operator, unfortunate.
+      let neighbors = [(0, 1), (2,1), (1, 0), (1, 2)];
+      for &(i, j) in neighbors.iter() {
+        // don't want to underflow
+        if x0 + i == 0 { continue; }
+        if y0 + j == 0 { continue; }
+        let x1 = x0 + i - 1;
+        let y1 = y0 + j - 1;
+
+        if self.is_set(x1, y1) && !seen.contains(&(x1,y1)) {
+          stack.push((x1, y1));
+        }
+      }
+
+      self.turn_off(x0, y0);
+    }
+  }
+
+  // this is one-way
+  pub fn count_regions(&mut self) -> u32 {
+    let mut sum = 0;
+
+    for y in 0..self.content.len() {
+      for x in 0..(8 * self.content[y].len()) {
+        if self.is_set(x, y) {
+          sum += 1;
+          self.flip_region(x, y);
+        }
+      }
+    }
+
+    return sum;
+  }
+
+  // the most significant bit is the leftmost square of each byte
+  fn bit(x: usize) -> u8 {
+    return 128u8 >> (x % 8);
+  }
+}
+
+impl Debug for BitBoard {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    writeln!(f)?;
+    for block in &self.content {
+      for cell in block {
+        write!(f, "{:08b}", cell)?;
+      }
+      writeln!(f)?;
+    }
+    return Ok(());
+  }
+}
+
+#[cfg(test)]
+mod point_tests {
+  use super::*;
+
+  #[test]
+  fn new_happy() {
+    let p = Point::new(1,2);
+    assert_eq!(p.x, 1);
+    assert_eq!(p.y, 2);
+  }
+
+  #[test]
+  fn neighborhood_test_origin() {
+    let p = Point::new(0,0);
+    let p_neighborhood = p.neighborhood(1);
+
+    assert!(p_neighborhood.contains(&Point::new(1,1)));
+    assert!(p_neighborhood.contains(&Point::new(1,0)));
+    assert!(p_neighborhood.contains(&Point::new(1,-1)));
+    assert!(p_neighborhood.contains(&Point::new(0,1)));
+    assert!(p_neighborhood.contains(&Point::new(0,-1)));
+    assert!(p_neighborhood.contains(&Point::new(-1,1)));
+    assert!(p_neighborhood.contains(&Point::new(-1,0)));
+    assert!(p_neighborhood.contains(&Point::new(-1,-1)));
+
+    assert!(!p_neighborhood.contains(&p));
+  }
+
+  #[test]
+  fn neighborhood_test_affine_point() {
+    let p = Point::new(1,2);
+    let p_neighborhood = p.neighborhood(1);
+
+    assert!(p_neighborhood.contains(&Point::new(1,3)));
+    assert!(p_neighborhood.contains(&Point::new(1,1)));
+    assert!(p_neighborhood.contains(&Point::new(0,3)));
+    assert!(p_neighborhood.contains(&Point::new(0,2)));
+    assert!(p_neighborhood.contains(&Point::new(0,1)));
+    assert!(p_neighborhood.contains(&Point::new(2,3)));
+    assert!(p_neighborhood.contains(&Point::new(2,2)));
+    assert!(p_neighborhood.contains(&Point::new(2,1)));
+
+    assert!(!p_neighborhood.contains(&p));
+  }
+
+  #[test]
+  fn adjacent_is_the_neighborhood_without_diagonals() {
+    let p = Point::new(1,2);
+    let adjacent = p.adjacent();
+
+    assert_eq!(adjacent.len(), 4);
+    for q in p.neighborhood(1) {
+      assert_eq!(adjacent.contains(&q), q.x == p.x || q.y == p.y);
+    }
+  }
+
+  #[test]
+  fn steps_are_adjacent() {
+    let p = Point::new(1,2);
+    let steps: Vec<Point> = [Direction::Right, Direction::Up, Direction::Left, Direction::Down]
+      .iter().map(|d| p.step(*d)).collect();
+
+    assert_eq!(steps, p.adjacent());
+  }
+}
+
+#[cfg(test)]
+mod bitboard_test {
+  use super::*;
+
+  #[test]
+  fn is_set() {
+    // something like
+    // 01000000
+    // 00000000
+    // 00100000
+    // 00000000
+    let bb = BitBoard { content: vec![
+        vec![64],
+        vec![0],
+        vec![32],
+        vec![0]
+    ]};
+
+    assert!(bb.is_set(1,0));
+    assert!(!bb.is_set(4,1));
+    assert!(bb.is_set(2,2));
+    assert!(!bb.is_set(3,3));
+    assert!(!bb.is_set(3,103));
+  }
+
+  #[test]
+  fn flip() {
+    // something like
+    // 01000000
+    // 00000000
+    // 00100000
+    // 00000000
+    let mut bb = BitBoard { content: vec![
+        vec![64],
+        vec![0],
+        vec![32],
+        vec![0]
+    ]};
+
+    assert!(bb.is_set(2,2));
+    bb.flip(2,2);
+    assert!(!bb.is_set(2,2));
+    bb.flip(2,2);
+    assert!(bb.is_set(2,2));
+  }
+
+  #[test]
+  fn region_large() {
+    // something like
+    // 11000011 00000001
+    // 10000000 10110110
+    // 11100000 00000000
+    // 00000000 11111111
+    let bb = BitBoard { content: vec![
+        vec![195 , 1],
+        vec![128, 182],
+        vec![224, 0],
+        vec![0, 255]
+    ]};
+
+    assert!(bb.is_set(1,0));
+    assert!(bb.is_set(2,2));
+    assert!(bb.is_set(6,0));
+    assert!(bb.is_set(7,0));
+
+    assert!(bb.is_set(15,0));
+    assert!(bb.is_set(8,1));
+    assert!(!bb.is_set(8,2));
+    assert!(bb.is_set(11,3));
+  }
+
+  #[test]
+  fn region_count() {
+    // something like
+    // 11000011 00000001
+    // 10000000 11111111
+    // 11100000 00000000
+    // 00000000 11111111
+    let mut bb = BitBoard { content: vec![
+        vec![195 , 1],
+        vec![128, 255],
+        vec![224, 0],
+        vec![0, 255]
+    ]};
+
+    assert_eq!(bb.count_regions(), 4);
+  }
+
+  #[test]
+  fn all_possible_is_set_poistions() {
+    let bb = BitBoard { content: vec![
+        vec![128, 0],
+        vec![64, 0],
+        vec![32, 0],
+        vec![16, 0],
+        vec![8, 0],
+        vec![4, 0],
+        vec![2, 0],
+        vec![1, 0],
+        vec![0, 128],
+        vec![0, 64],
+        vec![0, 32],
+        vec![0, 16],
+        vec![0, 8],
+        vec![0, 4],
+        vec![0, 2],
+        vec![0, 1]
+    ]};
+
+    for i in 0..16 {
+      assert!(bb.is_set(i,i));
+    }
+  }
+
+  #[test]
+  fn flip_region() {
+    // something like
+    // 01100010
+    // 01000000
+    // 01110000
+    // 00000000
+    let mut bb = BitBoard { content: vec![
+        vec![98],
+        vec![64],
+        vec![112],
+        vec![0]
+    ]};
+
+    println!("{:?}", bb);
+
+    assert!(bb.is_set(6,0)); // isolated point
+
+    assert!(bb.is_set(3,2)); // lower bar
+    assert!(bb.is_set(2,2)); // lower bar
+    assert!(bb.is_set(1,2)); // lower bar
+
+    assert!(bb.is_set(1,1)); // center
+
+    assert!(bb.is_set(1,0)); // upper bar
+    assert!(bb.is_set(2,0)); // upper bar
+
+    bb.flip_region(1,1);
+    println!("{:?}", bb);
+
+    assert!(bb.is_set(6,0)); // isolated point still set
+
+    assert!(!bb.is_set(3,2)); // lower bar unset
+    assert!(!bb.is_set(2,2)); // lower bar unset
+    assert!(!bb.is_set(1,2)); // lower bar unset
+
+    assert!(!bb.is_set(1,1)); // upper bar unset
+
+    assert!(!bb.is_set(1,0)); // upper bar unset
+    assert!(!bb.is_set(2,0)); // upper bar unset
+  }
+
+  #[test]
+  fn turn_on_and_off() {
+    let mut bb = BitBoard::new(vec![vec![0]]);
+
+    bb.turn_on(3,0);
+    bb.turn_on(3,0);
+    assert!(bb.is_set(3,0));
+    assert_eq!(bb.live_squares(), 1);
+
+    bb.turn_off(3,0);
+    assert!(bb.is_unset(3,0));
+    assert_eq!(bb.live_squares(), 0);
+  }
+}
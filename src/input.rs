@@ -0,0 +1,59 @@
+use std::fs::File;
+use std::io;
+use std::io::prelude::*;
+
+// reads the whole file into a string, panics if it can't be found or read
+pub fn read_file(path: &str) -> String {
+  let mut file = File::open(path).expect("file not found");
+  let mut content = String::new();
+  file.read_to_string(&mut content).expect("Something went wrong reading input file");
+
+  return content;
+}
+
+// the path to a file in a day's data directory, e.g., `day_path(9, "test1")` is `data/day9/test1`
+pub fn day_path(day: u8, name: &str) -> String {
+  return format!("data/day{}/{}", day, name);
+}
+
+pub fn read_day_file(day: u8, name: &str) -> String {
+  return read_file(&day_path(day, name));
+}
+
+// a path given on the command line, `-` is standard input, errors are left to the caller
+pub fn read_source(source: &str) -> io::Result<String> {
+  let mut content = String::new();
+  if source == "-" {
+    io::stdin().read_to_string(&mut content)?;
+  } else {
+    File::open(source)?.read_to_string(&mut content)?;
+  }
+  return Ok(content);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn day_path_happy() {
+    assert_eq!(day_path(9, "test1"), "data/day9/test1");
+  }
+
+  #[test]
+  fn read_day_file_happy() {
+    assert_eq!(read_day_file(9, "test1").trim(), "{}");
+  }
+
+  #[test]
+  #[should_panic]
+  fn read_file_missing() {
+    read_file("data/day0/nothing_here");
+  }
+
+  #[test]
+  fn read_source_path() {
+    assert_eq!(read_source("data/day9/test1").unwrap().trim(), "{}");
+    assert!(read_source("data/day0/nothing_here").is_err());
+  }
+}
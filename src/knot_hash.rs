@@ -1,63 +1,10 @@
-use std::fs::File;
-use std::io::prelude::*;
-use std::str::FromStr;
-
-fn main() {
-  println!("Advent of Code Day 10");
-  println!("");
-  println!("http://adventofcode.com/2017/day/10");
-  println!("");
-
-  let mut file = File::open("data/day10/input").expect("file not found");
-  let mut content = String::new();
-  file.read_to_string(&mut content).expect("Something went wrong reading input file");
-
-  part1(&content);
-  part2(&content);
-}
-
-fn part1(input: &String) {
-  let mut c = Circular::new((0..255).collect());
-  for shift_str in input.trim().split(',') {
-    let shift = parse_as::<usize>(&String::from(shift_str));
-    c.reverse_and_skip(shift);
-  }
-  println!("Part 1: {}", c.get(0) as i32 * c.get(1) as i32);
-}
-
-
-fn part2(input: &String) {
-  let mut v = vec![];
-  for i in 0..256 {
-    v.push(i as u8);
-  }
-  let mut c = Circular::new(v);
-  let mut parsed_input : Vec<u8> = input.trim().bytes().collect();
-  let mut appendage : Vec<u8> = vec![17,31,73,47,23];
-
-  parsed_input.append(&mut appendage);
-
-  for _ in 0..64 {
-    for shift in parsed_input.to_owned() {
-      c.reverse_and_skip(shift as usize);
-    }
-  }
-
-
-  println!("Part 2: {:?} -- Convert this to Hex by hand.", c.condense());
-}
-
-fn parse_as<T : FromStr>(input: &String) -> T {
-  let parsed : Result<T, T::Err> = input.parse();
-  match parsed {
-    Ok(d) => return d,
-    Err(_) => panic!("Failed to parse {}", input)
-  }
-}
+// The knot hash from day 10, which day 14 reuses to build its disk grid.
 
+// the lengths appended to every input before hashing
+const SUFFIX: [u8; 5] = [17, 31, 73, 47, 23];
 
 #[derive(Debug,PartialEq,Eq)]
-struct Circular {
+pub struct Circular {
   content: Vec<u8>,
   skip: usize,
   pointer: usize
@@ -65,7 +12,7 @@ struct Circular {
 
 // every operation will rotate the list such that the next start point pointer is _always_ zero.
 impl Circular {
-  fn new(content: Vec<u8>) -> Circular {
+  pub fn new(content: Vec<u8>) -> Circular {
     return Circular {
       content: content,
       skip: 0,
@@ -73,6 +20,15 @@ impl Circular {
     };
   }
 
+  // the list 0..255 (inclusive) that both days start from
+  pub fn standard() -> Circular {
+    let mut v = vec![];
+    for i in 0..256 {
+      v.push(i as u8);
+    }
+    return Circular::new(v);
+  }
+
   pub fn reverse_and_skip(&mut self, length: usize) {
     let size = self.size();
 
@@ -91,7 +47,7 @@ impl Circular {
     for i in 0..16 {
       let mut chunk: u8 = 0;
       for j in 0..16 {
-        chunk ^= self.content[i*16 + j] as u8;
+        chunk ^= self.content[i*16 + j];
       }
       out.push(chunk);
     }
@@ -104,28 +60,43 @@ impl Circular {
   }
 
   pub fn get(&self, idx: usize) -> u8 {
-    let result = self.content[idx].to_owned();
-    return result;
+    return self.content[idx];
   }
 
   fn size(&self) -> usize {
     return self.content.len();
   }
+}
 
+// the full 64-round dense hash of the given string
+pub fn hash(input: &str) -> Vec<u8> {
+  let mut c = Circular::standard();
+  let mut parsed_input : Vec<u8> = input.trim().bytes().collect();
+
+  parsed_input.extend_from_slice(&SUFFIX);
+
+  for _ in 0..64 {
+    for shift in &parsed_input {
+      c.reverse_and_skip(*shift as usize);
+    }
+  }
+
+  return c.condense();
+}
+
+// lowercase, two digits per byte
+pub fn to_hex(bytes: &[u8]) -> String {
+  let mut out = String::new();
+  for b in bytes {
+    out.push_str(&format!("{:02x}", b));
+  }
+  return out;
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
-  //fn read_test_file(filename: &str) -> String {
-    //let mut file = File::open(filename).expect("file not found");
-    //let mut content = String::new();
-    //file.read_to_string(&mut content).expect("Something went wrong reading input file");
-
-    //return content;
-  //}
-
   #[test]
   fn test() {
     let mut t = Circular::new(vec![0,1,2,3,4]);
@@ -150,7 +121,32 @@ mod tests {
     assert_eq!(t.content, vec![3,4,2,1,0]);
     assert_eq!(t.get(0) * t.get(1), 12);
     assert_eq!(t.get_pointer(), 0);
+  }
 
+  #[test]
+  fn standard_has_256_entries() {
+    let c = Circular::standard();
+    assert_eq!(c.size(), 256);
+    assert_eq!(c.get(255), 255);
+  }
 
+  #[test]
+  fn hash_of_empty_string() {
+    // from the day 10 examples, the empty string hashes to a2582a3a0e66e6e86e3812dcb672a272
+    let expected = vec![0xa2, 0x58, 0x2a, 0x3a, 0x0e, 0x66, 0xe6, 0xe8,
+                        0x6e, 0x38, 0x12, 0xdc, 0xb6, 0x72, 0xa2, 0x72];
+    assert_eq!(hash(""), expected);
+  }
+
+  #[test]
+  fn to_hex_pads_each_byte() {
+    assert_eq!(to_hex(&[0x07, 0xff, 0x40]), "07ff40");
+  }
+
+  #[test]
+  fn hash_examples() {
+    assert_eq!(to_hex(&hash("AoC 2017")), "33efeb34ea91902bb2f59c9920caa6cd");
+    assert_eq!(to_hex(&hash("1,2,3")), "3efbe78a8d82f29979031a4aa0b16a9d");
+    assert_eq!(to_hex(&hash("1,2,4")), "63960835bcdc130f0b66d7ff4f6a5a8e");
   }
 }
@@ -0,0 +1,21 @@
+//! Advent of Code 2017
+//!
+//! The solvers for each day live in `days`, the bits they have in common (reading input, parsing
+//! numbers, the knot hash, grids and graphs) live in the top-level modules so they only need to
+//! be written once.
+
+#[macro_use] extern crate lazy_static;
+extern crate regex;
+extern crate num_bigint;
+
+pub mod input;
+pub mod parsing;
+pub mod knot_hash;
+pub mod grid;
+pub mod graph;
+pub mod solution;
+pub mod observe;
+pub mod answers;
+pub mod bench;
+pub mod generate;
+pub mod days;
@@ -0,0 +1,381 @@
+// The `advent` command line: runs any day's solver and prints the answers as tab separated
+// `day part answer` lines, or JSON records, so scripts can consume them directly.
+//
+//     advent list
+//     advent run <day> [--part 1|2] [--input PATH|-] [--format tsv|json]
+//     advent all [--format tsv|json]
+//     advent verify [day]
+//     advent bench [day] [--iterations N] [--format text|json|csv] [--output PATH] [--input PATH|-]
+//     advent generate <day> [--size N] [--seed N]
+
+extern crate advent;
+
+use std::env;
+use std::fs;
+use std::process;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use advent::answers;
+use advent::answers::Outcome;
+use advent::bench;
+use advent::days;
+use advent::generate;
+use advent::input;
+use advent::solution::{Answer, SolveError, Solver};
+
+const USAGE: &str = "usage:
+  advent list                                   list every day and its puzzle
+  advent run <day> [--part 1|2] [--input PATH|-] [--format tsv|json]
+                                                run one day, both parts unless --part is given,
+                                                against data/dayN/input, PATH or stdin (-)
+  advent all [--format tsv|json]                run every day against its own input
+  advent verify [day]                           check every day, or just one, against data/dayN/answers
+  advent bench [day] [--iterations N] [--format text|json|csv] [--output PATH] [--input PATH|-]
+                                                time parse and each part, every day unless one is given
+  advent generate <day> [--size N] [--seed N]   print a random valid input for a day, 100 items big
+                                                by default, the same one every time for a seed";
+
+fn main() {
+  let args: Vec<String> = env::args().skip(1).collect();
+
+  match args.first().map(|s| s.as_str()) {
+    Some("list") => list(),
+    Some("run") => run(&args[1..]),
+    Some("all") => all(&args[1..]),
+    Some("verify") => verify(&args[1..]),
+    Some("bench") => bench(&args[1..]),
+    Some("generate") => generate(&args[1..]),
+    Some("help") | Some("--help") | Some("-h") => println!("{}", USAGE),
+    _ => usage_error("expected one of `list`, `run`, `all`, `verify`, `bench` or `generate`")
+  }
+}
+
+// how answers are printed: `day part answer` lines, or one JSON record per line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+  Tsv,
+  Json
+}
+
+fn list() {
+  for solver in days::all() {
+    println!("{}\t{}", solver.day(), solver.title());
+  }
+}
+
+fn run(args: &[String]) {
+  let mut day = None;
+  let mut part = None;
+  let mut path = None;
+  let mut format = Format::Tsv;
+
+  let mut iter = args.iter();
+  while let Some(arg) = iter.next() {
+    match arg.as_str() {
+      "--part" => part = Some(parse_part(iter.next())),
+      "--format" => format = parse_format(iter.next()),
+      "--input" => match iter.next() {
+        Some(p) => path = Some(p.to_owned()),
+        None => usage_error("--input needs a path, or - for stdin")
+      },
+      d if day.is_none() => day = Some(parse_day(d)),
+      other => usage_error(&format!("unexpected argument `{}`", other))
+    }
+  }
+
+  let solver = match day {
+    Some(d) => d,
+    None => usage_error("run needs a day")
+  };
+
+  let (source, content) = match path {
+    Some(p) => read_input(&p),
+    None => default_input(solver)
+  };
+
+  warn(&source, solver, &content);
+  let solved = match part {
+    Some(p) => solver.solve_part_timed(p, &content).map(|(answer, elapsed)| print_answer(format, solver.day(), p, &answer, elapsed)),
+    None => print_both(format, solver, &content)
+  };
+
+  if let Err(e) = solved {
+    report(&source, &content, &e);
+    process::exit(1);
+  }
+}
+
+// keeps going past days with malformed input, but still fails at the end
+fn all(args: &[String]) {
+  let format = match args {
+    [] => Format::Tsv,
+    [flag, f] if flag == "--format" => parse_format(Some(f)),
+    _ => usage_error("all only takes --format")
+  };
+
+  let mut failed = false;
+  for solver in days::all() {
+    let (source, content) = default_input(solver);
+    warn(&source, solver, &content);
+    if let Err(e) = print_both(format, solver, &content) {
+      report(&source, &content, &e);
+      failed = true;
+    }
+  }
+
+  if failed {
+    process::exit(1);
+  }
+}
+
+// one `day file part result` line per known answer, with the difference under any mismatch
+fn verify(args: &[String]) {
+  let solvers = match args {
+    [] => days::all(),
+    [day] => vec![parse_day(day)],
+    _ => usage_error("verify takes at most one day")
+  };
+
+  let (mut passed, mut mismatched, mut failed) = (0, 0, 0);
+  for solver in solvers {
+    let known = match answers::load(solver.day()) {
+      Some(Ok(k)) => k,
+      Some(Err(e)) => {
+        let source = input::day_path(solver.day(), answers::MANIFEST);
+        report(&source, &input::read_file(&source), &SolveError::from(e));
+        failed += 1;
+        continue;
+      },
+      None => {
+        println!("{}	-	-	fail", solver.day());
+        println!("  no {}", input::day_path(solver.day(), answers::MANIFEST));
+        failed += 1;
+        continue;
+      }
+    };
+
+    for k in &known {
+      match answers::check(solver, k) {
+        Outcome::Pass => {
+          println!("{}	{}	{}	pass", solver.day(), k.file, k.part);
+          passed += 1;
+        },
+        Outcome::Mismatch(actual) => {
+          println!("{}	{}	{}	mismatch", solver.day(), k.file, k.part);
+          println!("  - {}", k.answer);
+          println!("  + {}", actual);
+          mismatched += 1;
+        },
+        Outcome::Fail(why) => {
+          println!("{}	{}	{}	fail", solver.day(), k.file, k.part);
+          println!("  {}", why);
+          failed += 1;
+        }
+      }
+    }
+  }
+
+  println!("{} passed, {} mismatched, {} failed", passed, mismatched, failed);
+  if mismatched + failed > 0 {
+    process::exit(1);
+  }
+}
+
+fn bench(args: &[String]) {
+  let mut solvers = None;
+  let mut iterations = 10;
+  let mut format = "text";
+  let mut output = None;
+  let mut path = None;
+
+  let mut iter = args.iter();
+  while let Some(arg) = iter.next() {
+    match arg.as_str() {
+      "--iterations" => match iter.next().and_then(|n| n.parse::<usize>().ok()) {
+        Some(n) if n > 0 => iterations = n,
+        _ => usage_error("--iterations needs a number above 0")
+      },
+      "--format" => match iter.next().map(|f| f.as_str()) {
+        Some(f @ "text") | Some(f @ "json") | Some(f @ "csv") => format = f,
+        _ => usage_error("--format must be text, json or csv")
+      },
+      "--output" => match iter.next() {
+        Some(p) => output = Some(p.to_owned()),
+        None => usage_error("--output needs a path")
+      },
+      "--input" => match iter.next() {
+        Some(p) => path = Some(p.to_owned()),
+        None => usage_error("--input needs a path, or - for stdin")
+      },
+      d if solvers.is_none() => solvers = Some(vec![parse_day(d)]),
+      other => usage_error(&format!("unexpected argument `{}`", other))
+    }
+  }
+
+  if path.is_some() && solvers.is_none() {
+    usage_error("--input needs a day to bench");
+  }
+
+  let mut benches = vec![];
+  for solver in solvers.unwrap_or_else(days::all) {
+    let (source, content) = match path {
+      Some(ref p) => read_input(p),
+      None => default_input(solver)
+    };
+    match bench::run(solver, &content, iterations) {
+      Ok(b) => benches.push(b),
+      Err(e) => {
+        report(&source, &content, &e);
+        process::exit(1);
+      }
+    }
+  }
+
+  let report = match format {
+    "json" => bench::to_json(&benches),
+    "csv" => bench::to_csv(&benches),
+    _ => bench::to_text(&benches)
+  };
+
+  match output {
+    Some(path) => {
+      if let Err(e) = fs::write(&path, report) {
+        eprintln!("advent: couldn't write {}: {}", path, e);
+        process::exit(1);
+      }
+    },
+    None => print!("{}", report)
+  }
+}
+
+fn generate(args: &[String]) {
+  let mut day = None;
+  let mut size = 100;
+  let mut seed = None;
+
+  let mut iter = args.iter();
+  while let Some(arg) = iter.next() {
+    match arg.as_str() {
+      "--size" => match iter.next().and_then(|n| n.parse::<usize>().ok()) {
+        Some(n) if n > 0 => size = n,
+        _ => usage_error("--size needs a number above 0")
+      },
+      "--seed" => match iter.next().and_then(|n| n.parse::<u64>().ok()) {
+        Some(n) => seed = Some(n),
+        None => usage_error("--seed needs a number")
+      },
+      d if day.is_none() => day = Some(parse_day(d)),
+      other => usage_error(&format!("unexpected argument `{}`", other))
+    }
+  }
+
+  let solver = match day {
+    Some(solver) => solver,
+    None => usage_error("generate needs a day")
+  };
+
+  // without a seed, pick one and say which, so an interesting input can be made again
+  let seed = match seed {
+    Some(seed) => seed,
+    None => {
+      let seed = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos() as u64).unwrap_or(0);
+      eprintln!("advent: seed {}", seed);
+      seed
+    }
+  };
+
+  let options = generate::Options { size: size, seed: seed };
+  print!("{}", generate::generate(solver.day(), &options).expect("every day has a generator"));
+}
+
+// as `default_input`, but from the command line
+fn read_input(path: &str) -> (String, String) {
+  match input::read_source(path) {
+    Ok(content) if path == "-" => return (String::from("<stdin>"), content),
+    Ok(content) => return (path.to_owned(), content),
+    Err(e) => {
+      eprintln!("advent: couldn't read {}: {}", path, e);
+      process::exit(1);
+    }
+  }
+}
+
+// where the input came from, for diagnostics, along with the input itself
+fn default_input(solver: &dyn Solver) -> (String, String) {
+  match solver.input_file() {
+    Some(name) => (input::day_path(solver.day(), name), input::read_day_file(solver.day(), name)),
+    None => (String::from("(no input)"), String::new())
+  }
+}
+
+// each part printed as soon as it's answered, so part 1's answer is still given when part 2 has
+// none; that parses the input once for each
+fn print_both(format: Format, solver: &dyn Solver, content: &str) -> Result<(), SolveError> {
+  for part in 1..3 {
+    let (answer, elapsed) = solver.solve_part_timed(part, content)?;
+    print_answer(format, solver.day(), part, &answer, elapsed);
+  }
+  return Ok(());
+}
+
+fn warn(source: &str, solver: &dyn Solver, content: &str) {
+  for w in solver.warnings(content) {
+    eprintln!("advent: {}: warning: {}", source, w);
+  }
+}
+
+// the error, then for malformed input the offending line with a caret under the column it went
+// wrong at
+fn report(source: &str, content: &str, e: &SolveError) {
+  eprintln!("advent: {}: {}", source, e);
+  let e = match *e {
+    SolveError::Parse(ref e) => e,
+    SolveError::Unsolvable(_) => return
+  };
+  if let Some(line) = content.lines().nth(e.line - 1) {
+    eprintln!("  {}", line);
+    // keep any tabs so the caret lines up however wide they're shown
+    let pad: String = line.chars().take(e.column - 1).map(|c| if c == '\t' { '\t' } else { ' ' }).collect();
+    eprintln!("  {}^", pad);
+  }
+}
+
+// `elapsed` is how long the part took in seconds, parsing not included
+fn print_answer(format: Format, day: u8, part: u8, answer: &Answer, elapsed: Duration) {
+  match format {
+    Format::Tsv => println!("{}\t{}\t{}", day, part, answer),
+    Format::Json => println!("{{\"day\": {}, \"part\": {}, \"answer\": {}, \"elapsed\": {}}}",
+                             day, part, answer.to_json(), elapsed.as_secs_f64())
+  }
+}
+
+fn parse_day(arg: &str) -> &'static dyn Solver {
+  let trimmed = arg.trim_start_matches("day");
+  let found = trimmed.parse::<u8>().ok().and_then(days::find);
+  match found {
+    Some(solver) => return solver,
+    None => usage_error(&format!("`{}` is not a day between 1 and {}", arg, days::all().len()))
+  }
+}
+
+fn parse_format(arg: Option<&String>) -> Format {
+  match arg.map(|s| s.as_str()) {
+    Some("tsv") => return Format::Tsv,
+    Some("json") => return Format::Json,
+    _ => usage_error("--format must be tsv or json")
+  }
+}
+
+fn parse_part(arg: Option<&String>) -> u8 {
+  match arg.map(|s| s.as_str()) {
+    Some("1") => return 1,
+    Some("2") => return 2,
+    _ => usage_error("--part must be 1 or 2")
+  }
+}
+
+fn usage_error(message: &str) -> ! {
+  eprintln!("advent: {}", message);
+  eprintln!("{}", USAGE);
+  process::exit(2);
+}
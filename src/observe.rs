@@ -0,0 +1,134 @@
+// Hooks for watching the simulations that run step by step: day 5's jumps, day 6's
+// reallocations, day 8's registers and so on. Tracing, visualisation or metrics plug in here
+// without the solvers knowing anything about them.
+//
+// A simulation calls `before` and `after` around every step with itself, as it stands, and an
+// event saying what the step does. The solvers pass `()`, which watches nothing and costs nothing.
+
+use std::fmt::Debug;
+use std::io::Write;
+
+pub trait Observer<S: ?Sized, E: ?Sized> {
+  fn before(&mut self, _state: &S, _event: &E) {}
+  fn after(&mut self, _state: &S, _event: &E) {}
+}
+
+impl<S: ?Sized, E: ?Sized> Observer<S, E> for () {}
+
+// how many steps ran
+#[derive(Debug, PartialEq, Eq)]
+pub struct Counter {
+  pub steps: usize
+}
+
+impl Counter {
+  pub fn new() -> Counter {
+    return Counter { steps: 0 };
+  }
+}
+
+impl<S: ?Sized, E: ?Sized> Observer<S, E> for Counter {
+  fn after(&mut self, _state: &S, _event: &E) {
+    self.steps += 1;
+  }
+}
+
+// every event, in the order they happened
+#[derive(Debug, PartialEq, Eq)]
+pub struct Recorder<E> {
+  pub events: Vec<E>
+}
+
+impl<E> Recorder<E> {
+  pub fn new() -> Recorder<E> {
+    return Recorder { events: vec![] };
+  }
+}
+
+impl<S: ?Sized, E: Clone> Observer<S, E> for Recorder<E> {
+  fn after(&mut self, _state: &S, event: &E) {
+    self.events.push(event.clone());
+  }
+}
+
+// writes each event as it happens, one per line, e.g. `Trace::new(io::stderr())`
+pub struct Trace<W: Write> {
+  out: W
+}
+
+impl<W: Write> Trace<W> {
+  pub fn new(out: W) -> Trace<W> {
+    return Trace { out: out };
+  }
+
+  pub fn into_inner(self) -> W {
+    return self.out;
+  }
+}
+
+impl<S: ?Sized, E: Debug + ?Sized, W: Write> Observer<S, E> for Trace<W> {
+  fn before(&mut self, _state: &S, event: &E) {
+    // a trace that can't be written shouldn't stop the run
+    let _ = writeln!(self.out, "{:?}", event);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // a toy simulation: counts up, one step at a time
+  fn count_to<O: Observer<i32, i32>>(n: i32, observer: &mut O) -> i32 {
+    let mut state = 0;
+    for _ in 0..n {
+      observer.before(&state, &1);
+      state += 1;
+      observer.after(&state, &1);
+    }
+    return state;
+  }
+
+  struct Seen {
+    pairs: Vec<(i32, i32)>
+  }
+
+  impl Observer<i32, i32> for Seen {
+    fn before(&mut self, state: &i32, _event: &i32) {
+      self.pairs.push((*state, -1));
+    }
+
+    fn after(&mut self, state: &i32, _event: &i32) {
+      self.pairs.last_mut().unwrap().1 = *state;
+    }
+  }
+
+  #[test]
+  fn unobserved() {
+    assert_eq!(count_to(3, &mut ()), 3);
+  }
+
+  #[test]
+  fn before_and_after() {
+    let mut seen = Seen { pairs: vec![] };
+    count_to(3, &mut seen);
+    assert_eq!(seen.pairs, vec![(0, 1), (1, 2), (2, 3)]);
+  }
+
+  #[test]
+  fn counter_and_recorder() {
+    let mut counter = Counter::new();
+    count_to(4, &mut counter);
+    assert_eq!(counter.steps, 4);
+
+    let mut recorder = Recorder::new();
+    count_to(2, &mut recorder);
+    assert_eq!(recorder.events, vec![1, 1]);
+  }
+
+  #[test]
+  fn trace() {
+    let mut trace = Trace::new(vec![]);
+    count_to(2, &mut trace);
+    assert_eq!(String::from_utf8(trace.into_inner()).unwrap(), "1\n1\n");
+  }
+}
@@ -0,0 +1,174 @@
+use std::any;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+// Where the input went wrong and what we wanted to see there instead. Lines and columns count
+// from 1, columns are in characters.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+  pub line: usize,
+  pub column: usize,
+  pub text: String,
+  pub expected: String
+}
+
+impl ParseError {
+  // an error at the very start of the text handed to the parser, callers that know better move it
+  // with `on_line` and `from_column`
+  pub fn new(text: &str, expected: &str) -> ParseError {
+    return ParseError {
+      line: 1,
+      column: 1,
+      text: String::from(text),
+      expected: String::from(expected)
+    };
+  }
+
+  // an error `offset` bytes into `input`, with the line and column worked out from there
+  pub fn at(input: &str, offset: usize, text: &str, expected: &str) -> ParseError {
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let column = before[line_start..].chars().count() + 1;
+
+    return ParseError::new(text, expected).on_line(line).from_column(column);
+  }
+
+  pub fn on_line(mut self, line: usize) -> ParseError {
+    self.line = line;
+    return self;
+  }
+
+  // the text this error was found in started at `column` of its line
+  pub fn from_column(mut self, column: usize) -> ParseError {
+    self.column += column - 1;
+    return self;
+  }
+}
+
+impl fmt::Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "line {}, column {}: expected {}, found `{}'", self.line, self.column, self.expected, self.text)
+  }
+}
+
+impl Error for ParseError {}
+
+// surrounding whitespace is ignored, anything else has to parse as a `T`
+pub fn parse_as<T : FromStr>(input: &str) -> Result<T, ParseError> {
+  let trimmed = input.trim();
+  let parsed : Result<T, T::Err> = trimmed.parse();
+  match parsed {
+    Ok(d) => return Ok(d),
+    Err(_) => {
+      let leading = input.len() - input.trim_start().len();
+      let expected = format!("a value of type {}", any::type_name::<T>());
+      return Err(ParseError::new(trimmed, &expected).from_column(input[..leading].chars().count() + 1));
+    }
+  }
+}
+
+// each whitespace separated token in the line, along with the column it starts at
+pub fn tokens(line: &str) -> Vec<(usize, &str)> {
+  let mut result = vec![];
+  let mut start = None;
+
+  for (col, (offset, c)) in line.char_indices().enumerate() {
+    match (start, c.is_whitespace()) {
+      (None, false) => start = Some((col + 1, offset)),
+      (Some((column, from)), true) => {
+        result.push((column, &line[from..offset]));
+        start = None;
+      },
+      _ => ()
+    }
+  }
+
+  if let Some((column, from)) = start {
+    result.push((column, &line[from..]));
+  }
+
+  return result;
+}
+
+// each `separator` separated field in the line, trimmed, along with the column it starts at
+pub fn fields(line: &str, separator: char) -> Vec<(usize, &str)> {
+  let mut result = vec![];
+  let mut column = 1;
+
+  for field in line.split(separator) {
+    let leading = field.len() - field.trim_start().len();
+    result.push((column + field[..leading].chars().count(), field.trim()));
+    column += field.chars().count() + 1;
+  }
+
+  return result;
+}
+
+// every whitespace separated token in the input, on however many lines
+pub fn parse_all<T : FromStr>(input: &str) -> Result<Vec<T>, ParseError> {
+  let mut result = vec![];
+
+  for (idx, line) in input.lines().enumerate() {
+    for (column, token) in tokens(line) {
+      let parsed = parse_as::<T>(token).map_err(|e| e.on_line(idx + 1).from_column(column))?;
+      result.push(parsed);
+    }
+  }
+
+  return Ok(result);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_as_happy() {
+    assert_eq!(parse_as::<i32>("-12"), Ok(-12));
+    assert_eq!(parse_as::<usize>(" 7\n"), Ok(7));
+  }
+
+  #[test]
+  fn parse_as_malformed() {
+    let e = parse_as::<u8>("  seven").unwrap_err();
+    assert_eq!(e.line, 1);
+    assert_eq!(e.column, 3);
+    assert_eq!(e.text, "seven");
+    assert_eq!(e.expected, "a value of type u8");
+  }
+
+  #[test]
+  fn display() {
+    let e = ParseError::new("x", "a number").on_line(3).from_column(5);
+    assert_eq!(e.to_string(), "line 3, column 5: expected a number, found `x'");
+  }
+
+  #[test]
+  fn at_finds_line_and_column() {
+    let e = ParseError::at("12\n3x4\n", 4, "x", "a digit");
+    assert_eq!((e.line, e.column), (2, 2));
+  }
+
+  #[test]
+  fn tokens_have_columns() {
+    assert_eq!(tokens("  5 40\t2 "), vec![(3, "5"), (5, "40"), (8, "2")]);
+    assert_eq!(tokens(""), vec![]);
+  }
+
+  #[test]
+  fn fields_have_columns() {
+    assert_eq!(fields("0 <-> 2, 3", ','), vec![(1, "0 <-> 2"), (10, "3")]);
+    assert_eq!(fields("ne,ne,s", ','), vec![(1, "ne"), (4, "ne"), (7, "s")]);
+  }
+
+  #[test]
+  fn parse_all_reports_line_and_column() {
+    assert_eq!(parse_all::<i32>("0\n3\n-3"), Ok(vec![0, 3, -3]));
+
+    let e = parse_all::<i32>("0 2\n7 x").unwrap_err();
+    assert_eq!((e.line, e.column), (2, 3));
+    assert_eq!(e.text, "x");
+  }
+}
@@ -0,0 +1,350 @@
+// A common shape for every day's solver, so tooling can run any of them the same way.
+
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use knot_hash;
+use parsing::ParseError;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Answer {
+  Number(i64),
+  Text(String),
+  // a digest, displayed as lowercase hex
+  Hex(Vec<u8>)
+}
+
+impl fmt::Display for Answer {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      Answer::Number(n) => write!(f, "{}", n),
+      Answer::Text(ref s) => write!(f, "{}", s),
+      Answer::Hex(ref bytes) => write!(f, "{}", knot_hash::to_hex(bytes))
+    }
+  }
+}
+
+impl Answer {
+  // numbers as JSON numbers, everything else as a JSON string
+  pub fn to_json(&self) -> String {
+    match *self {
+      Answer::Number(n) => return n.to_string(),
+      _ => return json_string(&self.to_string())
+    }
+  }
+}
+
+fn json_string(s: &str) -> String {
+  let mut out = String::from("\"");
+  for c in s.chars() {
+    match c {
+      '"' => out.push_str("\\\""),
+      '\\' => out.push_str("\\\\"),
+      '\n' => out.push_str("\\n"),
+      '\t' => out.push_str("\\t"),
+      c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+      c => out.push(c)
+    }
+  }
+  out.push('"');
+  return out;
+}
+
+impl From<i32> for Answer {
+  fn from(n: i32) -> Answer { return Answer::Number(n as i64); }
+}
+
+impl From<u32> for Answer {
+  fn from(n: u32) -> Answer { return Answer::Number(n as i64); }
+}
+
+impl From<i64> for Answer {
+  fn from(n: i64) -> Answer { return Answer::Number(n); }
+}
+
+impl From<u64> for Answer {
+  fn from(n: u64) -> Answer { return Answer::Number(n as i64); }
+}
+
+impl From<usize> for Answer {
+  fn from(n: usize) -> Answer { return Answer::Number(n as i64); }
+}
+
+impl From<String> for Answer {
+  fn from(s: String) -> Answer { return Answer::Text(s); }
+}
+
+impl<'a> From<&'a str> for Answer {
+  fn from(s: &'a str) -> Answer { return Answer::Text(String::from(s)); }
+}
+
+// Why a day couldn't give an answer.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SolveError {
+  // the input isn't in the puzzle's format
+  Parse(ParseError),
+  // the input is well formed but has no answer, e.g. a day 2 row where no two values divide
+  Unsolvable(String)
+}
+
+impl fmt::Display for SolveError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      SolveError::Parse(ref e) => write!(f, "{}", e),
+      SolveError::Unsolvable(ref why) => write!(f, "{}", why)
+    }
+  }
+}
+
+impl Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+  fn from(e: ParseError) -> SolveError { return SolveError::Parse(e); }
+}
+
+// Each day parses its input once into whatever suits it, then answers both parts from that.
+pub trait Solution {
+  type Input;
+
+  fn day(&self) -> u8;
+  fn title(&self) -> &'static str;
+
+  // the file under `data/dayN/` holding the puzzle input, `None` if the day doesn't read one
+  fn input_file(&self) -> Option<&'static str> {
+    return Some("input");
+  }
+
+  // malformed input is reported rather than panicked on
+  fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+
+  // anything in the input worth pointing out that doesn't stop it being solved, e.g. characters
+  // that are skipped over
+  fn warnings(&self, _input: &str) -> Vec<String> {
+    return vec![];
+  }
+
+  // Each part either always has an answer, `part1`, or can find there isn't one, `try_part1`.
+  // A day implements whichever suits it and gets the other; implementing neither recurses forever.
+  fn part1(&self, input: &Self::Input) -> Answer {
+    match self.try_part1(input) {
+      Ok(answer) => return answer,
+      Err(e) => panic!("{}, check input and retry.", e)
+    }
+  }
+
+  fn part2(&self, input: &Self::Input) -> Answer {
+    match self.try_part2(input) {
+      Ok(answer) => return answer,
+      Err(e) => panic!("{}, check input and retry.", e)
+    }
+  }
+
+  fn try_part1(&self, input: &Self::Input) -> Result<Answer, SolveError> {
+    return Ok(self.part1(input));
+  }
+
+  fn try_part2(&self, input: &Self::Input) -> Result<Answer, SolveError> {
+    return Ok(self.part2(input));
+  }
+}
+
+// Both answers, and how long parsing and each part took to get them.
+#[derive(Debug, Clone)]
+pub struct Timed {
+  pub parse: Duration,
+  pub part1: Duration,
+  pub part2: Duration,
+  pub answers: (Answer, Answer)
+}
+
+// `Solution` with the input type erased, so every day can sit in the same table.
+pub trait Solver {
+  fn day(&self) -> u8;
+  fn title(&self) -> &'static str;
+  fn input_file(&self) -> Option<&'static str>;
+  fn warnings(&self, input: &str) -> Vec<String>;
+
+  // parses the input and answers the given part, 1 or 2
+  fn solve(&self, part: u8, input: &str) -> Result<Answer, SolveError>;
+
+  // parses the input once and answers both parts
+  fn solve_both(&self, input: &str) -> Result<(Answer, Answer), SolveError>;
+
+  // as `solve`, along with how long the part took, not counting parsing
+  fn solve_part_timed(&self, part: u8, input: &str) -> Result<(Answer, Duration), SolveError>;
+
+  // as `solve_both`, timing each stage separately
+  fn solve_timed(&self, input: &str) -> Result<Timed, SolveError>;
+}
+
+impl<S: Solution> Solver for S {
+  fn day(&self) -> u8 {
+    return Solution::day(self);
+  }
+
+  fn title(&self) -> &'static str {
+    return Solution::title(self);
+  }
+
+  fn input_file(&self) -> Option<&'static str> {
+    return Solution::input_file(self);
+  }
+
+  fn warnings(&self, input: &str) -> Vec<String> {
+    return Solution::warnings(self, input);
+  }
+
+  fn solve(&self, part: u8, input: &str) -> Result<Answer, SolveError> {
+    return self.solve_part_timed(part, input).map(|(answer, _)| answer);
+  }
+
+  fn solve_part_timed(&self, part: u8, input: &str) -> Result<(Answer, Duration), SolveError> {
+    let parsed = self.parse(input)?;
+    let start = Instant::now();
+    let answer = match part {
+      1 => self.try_part1(&parsed)?,
+      2 => self.try_part2(&parsed)?,
+      p => panic!("There is no part {}, only 1 and 2", p)
+    };
+    return Ok((answer, start.elapsed()));
+  }
+
+  fn solve_both(&self, input: &str) -> Result<(Answer, Answer), SolveError> {
+    let parsed = self.parse(input)?;
+    return Ok((self.try_part1(&parsed)?, self.try_part2(&parsed)?));
+  }
+
+  fn solve_timed(&self, input: &str) -> Result<Timed, SolveError> {
+    let start = Instant::now();
+    let parsed = self.parse(input)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let part1 = self.try_part1(&parsed)?;
+    let part1_time = start.elapsed();
+
+    let start = Instant::now();
+    let part2 = self.try_part2(&parsed)?;
+    let part2_time = start.elapsed();
+
+    return Ok(Timed { parse: parse, part1: part1_time, part2: part2_time, answers: (part1, part2) });
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use parsing::parse_as;
+
+  struct Doubler;
+
+  impl Solution for Doubler {
+    type Input = i64;
+
+    fn day(&self) -> u8 { return 0; }
+    fn title(&self) -> &'static str { return "Doubler"; }
+
+    fn parse(&self, input: &str) -> Result<i64, ParseError> {
+      return parse_as(input);
+    }
+
+    fn part1(&self, input: &i64) -> Answer {
+      return Answer::from(input * 2);
+    }
+
+    fn part2(&self, input: &i64) -> Answer {
+      return Answer::from(format!("{}{}", input, input));
+    }
+  }
+
+  // part 2 has no answer for odd numbers
+  struct Halver;
+
+  impl Solution for Halver {
+    type Input = i64;
+
+    fn day(&self) -> u8 { return 0; }
+    fn title(&self) -> &'static str { return "Halver"; }
+
+    fn parse(&self, input: &str) -> Result<i64, ParseError> {
+      return parse_as(input);
+    }
+
+    fn part1(&self, input: &i64) -> Answer {
+      return Answer::from(*input);
+    }
+
+    fn try_part2(&self, input: &i64) -> Result<Answer, SolveError> {
+      if input % 2 != 0 {
+        return Err(SolveError::Unsolvable(format!("{} is odd", input)));
+      }
+      return Ok(Answer::from(input / 2));
+    }
+  }
+
+  #[test]
+  fn display() {
+    assert_eq!(Answer::Number(-3).to_string(), "-3");
+    assert_eq!(Answer::from("tknk").to_string(), "tknk");
+    assert_eq!(Answer::Hex(vec![0x0a, 0xff]).to_string(), "0aff");
+  }
+
+  #[test]
+  fn solver_dispatches_parts() {
+    let solver: &dyn Solver = &Doubler;
+    assert_eq!(solver.solve(1, "21"), Ok(Answer::Number(42)));
+    assert_eq!(solver.solve(2, "21"), Ok(Answer::from("2121")));
+    assert_eq!(solver.solve_both("4"), Ok((Answer::Number(8), Answer::from("44"))));
+    assert_eq!(solver.input_file(), Some("input"));
+  }
+
+  #[test]
+  fn to_json() {
+    assert_eq!(Answer::Number(-3).to_json(), "-3");
+    assert_eq!(Answer::from("tknk").to_json(), "\"tknk\"");
+    assert_eq!(Answer::Hex(vec![0x0a, 0xff]).to_json(), "\"0aff\"");
+    assert_eq!(Answer::from("a \"b\"\\\n").to_json(), "\"a \\\"b\\\"\\\\\\n\"");
+  }
+
+  #[test]
+  fn solve_part_timed_answers_one_part() {
+    assert_eq!(Doubler.solve_part_timed(2, "3").unwrap().0, Answer::from("33"));
+  }
+
+  #[test]
+  fn solve_timed_gives_both_answers() {
+    let timed = Doubler.solve_timed("4").unwrap();
+    assert_eq!(timed.answers, (Answer::Number(8), Answer::from("44")));
+    assert!(Doubler.solve_timed("four").is_err());
+  }
+
+  #[test]
+  fn solver_reports_malformed_input() {
+    match Doubler.solve(1, "twenty") {
+      Err(SolveError::Parse(e)) => assert_eq!(e.text, "twenty"),
+      other => panic!("expected a parse error, not {:?}", other)
+    }
+  }
+
+  #[test]
+  fn solver_reports_inputs_without_answers() {
+    assert_eq!(Halver.solve(1, "3"), Ok(Answer::Number(3)));
+    assert_eq!(Halver.solve(2, "4"), Ok(Answer::Number(2)));
+    assert_eq!(Halver.solve(2, "3"), Err(SolveError::Unsolvable(String::from("3 is odd"))));
+    assert!(Halver.solve_timed("3").is_err());
+    assert!(matches!(Halver.solve(2, "three"), Err(SolveError::Parse(_))));
+  }
+
+  #[test]
+  #[should_panic(expected = "3 is odd, check input and retry.")]
+  fn part_panics_without_an_answer() {
+    Halver.part2(&3);
+  }
+
+  #[test]
+  #[should_panic]
+  fn solver_rejects_part_3() {
+    let _ = Doubler.solve(3, "1");
+  }
+}
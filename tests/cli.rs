@@ -0,0 +1,61 @@
+// The `advent` command line end to end: what it prints and how it exits.
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+// `advent` with `args`, `input` on stdin
+fn advent(args: &[&str], input: &str) -> Output {
+  let mut child = Command::new(env!("CARGO_BIN_EXE_advent"))
+    .args(args)
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .expect("advent runs");
+  child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+  return child.wait_with_output().unwrap();
+}
+
+#[test]
+fn run_answers_from_stdin() {
+  let out = advent(&["run", "2", "--input", "-"], "5 9 2 8\n9 4 7 3\n3 8 6 5\n");
+  assert_eq!(out.status.code(), Some(0));
+  assert_eq!(String::from_utf8_lossy(&out.stdout), "2\t1\t18\n2\t2\t9\n");
+}
+
+#[test]
+fn day2_row_without_a_divisible_pair() {
+  for input in ["5 9 2 8\n3 5 7\n", "0 0\n"].iter() {
+    let out = advent(&["run", "2", "--input", "-"], input);
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert_eq!(out.status.code(), Some(1), "{}", stderr);
+    assert!(stderr.contains("advent: <stdin>: row "), "{}", stderr);
+    assert!(stderr.contains("no two values divide evenly\n"), "{}", stderr);
+    assert!(!stderr.contains("panicked"), "{}", stderr);
+  }
+}
+
+#[test]
+fn day3_target_past_the_largest_value() {
+  let out = advent(&["run", "3", "--input", "-"], "9223372036854775807\n");
+  let stderr = String::from_utf8_lossy(&out.stderr);
+  assert_eq!(out.status.code(), Some(1), "{}", stderr);
+  // part 1 still has an answer
+  assert_eq!(String::from_utf8_lossy(&out.stdout), "3\t1\t2891526306\n");
+  assert!(stderr.contains("the value for square 464 overflows an i64"), "{}", stderr);
+  assert!(!stderr.contains("panicked"), "{}", stderr);
+}
+
+#[test]
+fn day7_tower_without_an_answer() {
+  let out = advent(&["run", "7", "--input", "-"], "pbga (66)\n");
+  let stderr = String::from_utf8_lossy(&out.stderr);
+  assert_eq!(out.status.code(), Some(1), "{}", stderr);
+  assert_eq!(stderr, "advent: <stdin>: no program is at the bottom of the tower\n");
+
+  let out = advent(&["run", "7", "--input", "-"], "pbga (66) -> xhth, ebii\nxhth (57)\nebii (57)\n");
+  let stderr = String::from_utf8_lossy(&out.stderr);
+  assert_eq!(out.status.code(), Some(1), "{}", stderr);
+  assert_eq!(String::from_utf8_lossy(&out.stdout), "7\t1\tpbga\n");
+  assert_eq!(stderr, "advent: <stdin>: the tower is already balanced\n");
+}
@@ -1,5 +1,6 @@
 echo -n 'watchman-make '
-for bin in `ls src/bin/` ; do 
-  echo "-p 'src/bin/$bin' -t 'test --bin ${bin%*.rs}' --make 'clear ; cargo' \\"; 
+for day in `ls src/days/ | grep day` ; do 
+  echo "-p 'src/days/$day' -t 'test --lib days::${day%*.rs}::' --make 'clear ; cargo' \\"; 
 done
-echo '-p Cargo.toml -t update --make "clear ; cargo"' 
+echo "-p 'src/*.rs' -t 'test' --make 'clear ; cargo' \\"
+echo '-p Cargo.toml -t update --make "clear ; cargo"'